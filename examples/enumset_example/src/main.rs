@@ -21,6 +21,7 @@ use kinded::Kinded;
 /// - `skip_derive(Clone, Copy, PartialEq, Eq)` to avoid conflicts with enumset
 /// - `derive(EnumSetType)` to make the kind enum work with EnumSet
 /// - `attrs(enumset(...), repr(u8))` to configure enumset
+/// - `implies(...)` and `conflicts(...)` to declare relations between the permissions
 #[derive(Kinded)]
 #[kinded(
     skip_derive(Clone, Copy, PartialEq, Eq),
@@ -28,10 +29,19 @@ use kinded::Kinded;
     attrs(enumset(repr = "u8"), repr(u8))
 )]
 enum Permission {
-    Read { path: String },
-    Write { path: String },
-    Execute { command: String },
+    Read {
+        path: String,
+    },
+    Write {
+        path: String,
+    },
+    Execute {
+        command: String,
+    },
+    #[kinded(implies(Read, Write, Execute))]
     Admin,
+    #[kinded(implies(Read), conflicts(Write))]
+    ReadOnly,
 }
 
 /// Check if a permission is allowed by a permission set, and return a description.
@@ -42,6 +52,7 @@ fn check_permission(perm: &Permission, allowed: EnumSet<PermissionKind>) -> Stri
             Permission::Write { path } => format!("Allowed to write: {path}"),
             Permission::Execute { command } => format!("Allowed to execute: {command}"),
             Permission::Admin => "Admin access granted".to_owned(),
+            Permission::ReadOnly => "Read-only access granted".to_owned(),
         }
     } else {
         format!("Permission denied: {:?}", perm.kind())
@@ -118,4 +129,16 @@ fn main() {
         check_permission(&admin, admin_permissions),
         "Admin access granted"
     );
+
+    // Admin implies the other permissions, so they are granted together with it
+    let granted: EnumSet<PermissionKind> =
+        PermissionKind::closure(EnumSet::only(PermissionKind::Admin))
+            .into_iter()
+            .collect();
+    assert!(granted.contains(PermissionKind::Execute));
+    assert!(!granted.contains(PermissionKind::ReadOnly));
+
+    // ReadOnly conflicts with Write, which is implied by Admin
+    assert!(PermissionKind::validate(PermissionKind::ReadOnly | PermissionKind::Read).is_ok());
+    assert!(PermissionKind::validate(PermissionKind::ReadOnly | PermissionKind::Admin).is_err());
 }
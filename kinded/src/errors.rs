@@ -46,3 +46,169 @@ impl ::core::error::Error for ParseKindError {
         None
     }
 }
+
+/// An error which is returned when a kind does not match the kind of a given value,
+/// e.g. when a main enum is assembled from a kind and a payload of a different kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindMismatchError<K> {
+    expected: K,
+    actual: K,
+}
+
+impl<K> KindMismatchError<K> {
+    /// This method is used by `kinded` macro to construct the error and is not
+    /// recommend for a direct usage by users.
+    pub fn new(expected: K, actual: K) -> Self {
+        Self { expected, actual }
+    }
+
+    /// The kind that was requested.
+    pub fn expected(&self) -> &K {
+        &self.expected
+    }
+
+    /// The kind of the given value.
+    pub fn actual(&self) -> &K {
+        &self.actual
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::fmt::Display for KindMismatchError<K> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { expected, actual } = self;
+        write!(f, "Kind mismatch: expected {expected:?}, got {actual:?}")
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::error::Error for KindMismatchError<K> {}
+
+/// An error which is returned when a main enum value is constructed from a kind
+/// whose variant carries data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonUnitKindError<K> {
+    kind: K,
+}
+
+impl<K> NonUnitKindError<K> {
+    /// This method is used by `kinded` macro to construct the error and is not
+    /// recommend for a direct usage by users.
+    pub fn new(kind: K) -> Self {
+        Self { kind }
+    }
+
+    /// The kind that was given.
+    pub fn kind(&self) -> &K {
+        &self.kind
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::fmt::Display for NonUnitKindError<K> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind } = self;
+        write!(
+            f,
+            "Cannot construct a value from kind {kind:?}: the variant has data"
+        )
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::error::Error for NonUnitKindError<K> {}
+
+/// An error which is returned when a kind is converted into a subset kind type
+/// that does not contain it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotInSubsetError<K> {
+    kind: K,
+}
+
+impl<K> NotInSubsetError<K> {
+    /// This method is used by `kinded` macro to construct the error and is not
+    /// recommend for a direct usage by users.
+    pub fn new(kind: K) -> Self {
+        Self { kind }
+    }
+
+    /// The kind that was given.
+    pub fn kind(&self) -> &K {
+        &self.kind
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::fmt::Display for NotInSubsetError<K> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind } = self;
+        write!(f, "Kind {kind:?} is not in the subset")
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::error::Error for NotInSubsetError<K> {}
+
+/// An error which is returned when a set of kinds contains two kinds that conflict with each other,
+/// taking into account the kinds they imply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindConflictError<K> {
+    kind: K,
+    conflicting: K,
+}
+
+impl<K> KindConflictError<K> {
+    /// This method is used by `kinded` macro to construct the error and is not
+    /// recommend for a direct usage by users.
+    pub fn new(kind: K, conflicting: K) -> Self {
+        Self { kind, conflicting }
+    }
+
+    /// The kind that declares the conflict.
+    pub fn kind(&self) -> &K {
+        &self.kind
+    }
+
+    /// The kind it conflicts with.
+    pub fn conflicting(&self) -> &K {
+        &self.conflicting
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::fmt::Display for KindConflictError<K> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { kind, conflicting } = self;
+        write!(f, "Kind {kind:?} conflicts with {conflicting:?}")
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::error::Error for KindConflictError<K> {}
+
+/// An error which is returned when a value can't transition into another one,
+/// because the transition between their kinds is not declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransitionError<K> {
+    from: K,
+    to: K,
+}
+
+impl<K> TransitionError<K> {
+    /// This method is used by `kinded` macro to construct the error and is not
+    /// recommend for a direct usage by users.
+    pub fn new(from: K, to: K) -> Self {
+        Self { from, to }
+    }
+
+    /// The kind of the current value.
+    pub fn from(&self) -> &K {
+        &self.from
+    }
+
+    /// The kind of the next value.
+    pub fn to(&self) -> &K {
+        &self.to
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::fmt::Display for TransitionError<K> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { from, to } = self;
+        write!(f, "Transition from {from:?} to {to:?} is not allowed")
+    }
+}
+
+impl<K: ::core::fmt::Debug> ::core::error::Error for TransitionError<K> {}
@@ -0,0 +1,312 @@
+//! Kind types of the enums from the standard library and other crates, together with their
+//! `Kinded` implementations, so generic code can handle them like the derived enums.
+
+extern crate alloc;
+
+use crate::traits::{Kinded, KindedPath};
+use alloc::borrow::{Cow, ToOwned};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Bound;
+use kinded_macros::Kind;
+
+/// Kind of [`Option`].
+#[derive(Kind, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionKind {
+    Some,
+    None,
+}
+
+impl<T> Kinded for Option<T> {
+    type Kind = OptionKind;
+
+    fn kind(&self) -> OptionKind {
+        match self {
+            Some(_) => OptionKind::Some,
+            None => OptionKind::None,
+        }
+    }
+}
+
+impl<T> KindedPath for Option<T> {
+    type KindPath = OptionKind;
+
+    fn kind_path(&self) -> OptionKind {
+        self.kind()
+    }
+
+    fn all_kind_paths() -> Vec<OptionKind> {
+        Vec::from(OptionKind::all())
+    }
+}
+
+/// Kind of [`Result`].
+#[derive(Kind, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResultKind {
+    Ok,
+    Err,
+}
+
+impl<T, E> Kinded for Result<T, E> {
+    type Kind = ResultKind;
+
+    fn kind(&self) -> ResultKind {
+        match self {
+            Ok(_) => ResultKind::Ok,
+            Err(_) => ResultKind::Err,
+        }
+    }
+}
+
+impl<T, E> KindedPath for Result<T, E> {
+    type KindPath = ResultKind;
+
+    fn kind_path(&self) -> ResultKind {
+        self.kind()
+    }
+
+    fn all_kind_paths() -> Vec<ResultKind> {
+        Vec::from(ResultKind::all())
+    }
+}
+
+/// Kind of [`Ordering`].
+#[derive(Kind, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderingKind {
+    Less,
+    Equal,
+    Greater,
+}
+
+impl Kinded for Ordering {
+    type Kind = OrderingKind;
+
+    fn kind(&self) -> OrderingKind {
+        match self {
+            Ordering::Less => OrderingKind::Less,
+            Ordering::Equal => OrderingKind::Equal,
+            Ordering::Greater => OrderingKind::Greater,
+        }
+    }
+}
+
+impl KindedPath for Ordering {
+    type KindPath = OrderingKind;
+
+    fn kind_path(&self) -> OrderingKind {
+        self.kind()
+    }
+
+    fn all_kind_paths() -> Vec<OrderingKind> {
+        Vec::from(OrderingKind::all())
+    }
+}
+
+/// Kind of [`Bound`].
+#[derive(Kind, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundKind {
+    Included,
+    Excluded,
+    Unbounded,
+}
+
+impl<T> Kinded for Bound<T> {
+    type Kind = BoundKind;
+
+    fn kind(&self) -> BoundKind {
+        match self {
+            Bound::Included(_) => BoundKind::Included,
+            Bound::Excluded(_) => BoundKind::Excluded,
+            Bound::Unbounded => BoundKind::Unbounded,
+        }
+    }
+}
+
+impl<T> KindedPath for Bound<T> {
+    type KindPath = BoundKind;
+
+    fn kind_path(&self) -> BoundKind {
+        self.kind()
+    }
+
+    fn all_kind_paths() -> Vec<BoundKind> {
+        Vec::from(BoundKind::all())
+    }
+}
+
+/// Kind of [`Cow`].
+#[derive(Kind, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CowKind {
+    Borrowed,
+    Owned,
+}
+
+impl<B> Kinded for Cow<'_, B>
+where
+    B: ToOwned + ?Sized,
+{
+    type Kind = CowKind;
+
+    fn kind(&self) -> CowKind {
+        match self {
+            Cow::Borrowed(_) => CowKind::Borrowed,
+            Cow::Owned(_) => CowKind::Owned,
+        }
+    }
+}
+
+impl<B> KindedPath for Cow<'_, B>
+where
+    B: ToOwned + ?Sized,
+{
+    type KindPath = CowKind;
+
+    fn kind_path(&self) -> CowKind {
+        self.kind()
+    }
+
+    fn all_kind_paths() -> Vec<CowKind> {
+        Vec::from(CowKind::all())
+    }
+}
+
+/// Kind of [`serde_json::Value`].
+#[cfg(feature = "serde_json")]
+#[derive(Kind, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonValueKind {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+#[cfg(feature = "serde_json")]
+impl Kinded for serde_json::Value {
+    type Kind = JsonValueKind;
+
+    fn kind(&self) -> JsonValueKind {
+        use serde_json::Value;
+
+        match self {
+            Value::Null => JsonValueKind::Null,
+            Value::Bool(_) => JsonValueKind::Bool,
+            Value::Number(_) => JsonValueKind::Number,
+            Value::String(_) => JsonValueKind::String,
+            Value::Array(_) => JsonValueKind::Array,
+            Value::Object(_) => JsonValueKind::Object,
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl KindedPath for serde_json::Value {
+    type KindPath = JsonValueKind;
+
+    fn kind_path(&self) -> JsonValueKind {
+        self.kind()
+    }
+
+    fn all_kind_paths() -> Vec<JsonValueKind> {
+        Vec::from(JsonValueKind::all())
+    }
+}
+
+#[cfg(feature = "std")]
+mod io {
+    extern crate std;
+
+    use crate::traits::{Kind, Kinded, KindedPath};
+    use std::io::{Error, ErrorKind};
+    use std::vec::Vec;
+
+    /// `ErrorKind` is non-exhaustive, so only the kinds that are stable in Rust 1.85 are listed.
+    ///
+    /// `Error::kind()` can return kinds that are missing here: the ones stabilized after Rust 1.85
+    /// and the unstable ones that `std` uses internally (e.g. `Uncategorized`).
+    impl Kind for ErrorKind {
+        fn all() -> &'static [ErrorKind] {
+            &[
+                ErrorKind::NotFound,
+                ErrorKind::PermissionDenied,
+                ErrorKind::ConnectionRefused,
+                ErrorKind::ConnectionReset,
+                ErrorKind::HostUnreachable,
+                ErrorKind::NetworkUnreachable,
+                ErrorKind::ConnectionAborted,
+                ErrorKind::NotConnected,
+                ErrorKind::AddrInUse,
+                ErrorKind::AddrNotAvailable,
+                ErrorKind::NetworkDown,
+                ErrorKind::BrokenPipe,
+                ErrorKind::AlreadyExists,
+                ErrorKind::WouldBlock,
+                ErrorKind::NotADirectory,
+                ErrorKind::IsADirectory,
+                ErrorKind::DirectoryNotEmpty,
+                ErrorKind::ReadOnlyFilesystem,
+                ErrorKind::StaleNetworkFileHandle,
+                ErrorKind::InvalidInput,
+                ErrorKind::InvalidData,
+                ErrorKind::TimedOut,
+                ErrorKind::WriteZero,
+                ErrorKind::StorageFull,
+                ErrorKind::NotSeekable,
+                ErrorKind::QuotaExceeded,
+                ErrorKind::FileTooLarge,
+                ErrorKind::ResourceBusy,
+                ErrorKind::ExecutableFileBusy,
+                ErrorKind::Deadlock,
+                ErrorKind::CrossesDevices,
+                ErrorKind::TooManyLinks,
+                ErrorKind::ArgumentListTooLong,
+                ErrorKind::Interrupted,
+                ErrorKind::Unsupported,
+                ErrorKind::UnexpectedEof,
+                ErrorKind::OutOfMemory,
+                ErrorKind::Other,
+            ]
+        }
+    }
+
+    impl Kinded for ErrorKind {
+        type Kind = ErrorKind;
+
+        fn kind(&self) -> ErrorKind {
+            *self
+        }
+    }
+
+    impl Kinded for Error {
+        type Kind = ErrorKind;
+
+        fn kind(&self) -> ErrorKind {
+            Error::kind(self)
+        }
+    }
+
+    impl KindedPath for ErrorKind {
+        type KindPath = ErrorKind;
+
+        fn kind_path(&self) -> ErrorKind {
+            *self
+        }
+
+        fn all_kind_paths() -> Vec<ErrorKind> {
+            Vec::from(ErrorKind::all())
+        }
+    }
+
+    impl KindedPath for Error {
+        type KindPath = ErrorKind;
+
+        fn kind_path(&self) -> ErrorKind {
+            Error::kind(self)
+        }
+
+        fn all_kind_paths() -> Vec<ErrorKind> {
+            Vec::from(ErrorKind::all())
+        }
+    }
+}
@@ -121,6 +121,7 @@
 //! ### Derive traits
 //!
 //! By default the kind type implements the following traits: `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `Display`, `FromStr`, `From<T>`, `From<&T>`.
+//! It can also be compared with the main enum: `PartialEq<T>` and `PartialEq<&T>` (see [Comparing with kind](#comparing-with-kind)).
 //!
 //! Extra traits can be derived with `derive(..)` attribute:
 //!
@@ -162,7 +163,7 @@
 //!
 //! The following traits can be skipped:
 //! - Derived traits: `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`
-//! - Implemented traits: `Display`, `FromStr`, `From`
+//! - Implemented traits: `Display`, `FromStr`, `From`, `CrossPartialEq` (`PartialEq` between the main enum and the kind), `TryFrom` (`TryFrom<DrinkKind> for Drink`), `MainFromStr` (`FromStr for Drink`), `Kind` (`Kind` trait and `all()`, together with `Kinded` that requires it), `Kinded` (`Kinded` and `KindedPath` traits of the main enum)
 //!
 //! You can combine `skip_derive` with `derive` to replace default traits:
 //!
@@ -249,12 +250,13 @@
 //! ### FromStr trait
 //!
 //! The kind type implements `FromStr` trait. The implementation tries it's best to parse, checking all the possible cases mentioned above.
+//! With `ignore_case` attribute the case of the letters is ignored as a last resort, so `"very_HOT_black_tea"` is parsed too.
 //!
 //! ```
 //! use kinded::Kinded;
 //!
 //! #[derive(Kinded)]
-//! #[kinded(display = "snake_case")]
+//! #[kinded(display = "snake_case", ignore_case)]
 //! enum Drink {
 //!     VeryHotBlackTea,
 //!     Milk { fat: f64 },
@@ -269,6 +271,11 @@
 //!     "veryhotblacktea".parse::<DrinkKind>().unwrap(),
 //!     DrinkKind::VeryHotBlackTea
 //! );
+//!
+//! assert_eq!(
+//!     "very_HOT_black_tea".parse::<DrinkKind>().unwrap(),
+//!     DrinkKind::VeryHotBlackTea
+//! );
 //! ```
 //!
 //! The possible values are `"snake_case"`, `"camelCase"`, `"PascalCase"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, `"SCREAMING-KEBAB-CASE"`, `"Title Case"`, `"lowercase"`, `"UPPERCASE"`.
@@ -302,6 +309,803 @@
 //!
 //! Note: The original variant name and its case alternatives can still be parsed (e.g., `"LenUtf16Min"`, `"len_utf_16_min"`).
 //!
+//! ### Predicates
+//!
+//! With `predicates` attribute `is_<variant>()` methods are generated for both the main enum and the kind enum.
+//! The method names are the snake_case variant names prefixed with `is_`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(predicates)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     HotTea { variety: String },
+//! }
+//!
+//! let drink = Drink::Coffee("Espresso".to_owned());
+//! assert!(drink.is_coffee());
+//! assert!(!drink.is_hot_tea());
+//!
+//! assert!(DrinkKind::HotTea.is_hot_tea());
+//! ```
+//!
+//! If the names clash with methods you already have, the prefix can be changed:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(predicates(prefix = "is_kind_"))]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert!(Drink::Mate.is_kind_mate());
+//! assert!(DrinkKind::Coffee.is_kind_coffee());
+//! ```
+//!
+//! ### Payload accessors
+//!
+//! With `accessors` attribute the main enum gets methods to reach the data of its variants:
+//! `as_<variant>()`, `as_<variant>_mut()` and `into_<variant>()`.
+//! Variants with a single field return the field itself, variants with multiple fields return a tuple.
+//! Unit variants get no accessors.
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded, Debug)]
+//! #[kinded(accessors)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool },
+//! }
+//!
+//! let mut tea = Drink::Tea { variety: "Green".to_owned(), caffeine: true };
+//! assert_eq!(tea.as_tea(), Some((&"Green".to_owned(), &true)));
+//! assert_eq!(tea.as_coffee(), None);
+//!
+//! if let Some((_variety, caffeine)) = tea.as_tea_mut() {
+//!     *caffeine = false;
+//! }
+//!
+//! let coffee = Drink::Coffee("Espresso".to_owned());
+//! assert_eq!(coffee.into_coffee().unwrap(), "Espresso");
+//!
+//! // On mismatch the original value is given back
+//! assert!(matches!(Drink::Mate.into_coffee(), Err(Drink::Mate)));
+//! ```
+//!
+//! ### Borrowed view enums
+//!
+//! With `ref_enum` and `mut_enum` attributes kinded generates enums with the same variants as the main enum,
+//! but holding references to the data: `DrinkRef<'a>` and `DrinkMut<'a>`.
+//! They are obtained with `as_ref_enum()` and `as_mut_enum()` and implement `Kinded` with the same kind type:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(ref_enum, mut_enum)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool },
+//! }
+//!
+//! let mut tea = Drink::Tea { variety: "Green".to_owned(), caffeine: true };
+//!
+//! if let DrinkMut::Tea { caffeine, .. } = tea.as_mut_enum() {
+//!     *caffeine = false;
+//! }
+//!
+//! match tea.as_ref_enum() {
+//!     DrinkRef::Tea { variety, caffeine } => assert_eq!((variety.as_str(), *caffeine), ("Green", false)),
+//!     _ => unreachable!(),
+//! }
+//!
+//! assert_eq!(tea.as_ref_enum().kind(), DrinkKind::Tea);
+//! ```
+//!
+//! ### Kind and payload parts
+//!
+//! With `parts` attribute a value can be split into its kind and its data and assembled back.
+//! The data is held by the generated `DrinkPayload` enum:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded, Debug, PartialEq)]
+//! #[kinded(parts)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool },
+//! }
+//!
+//! let (kind, payload) = Drink::Coffee("Espresso".to_owned()).into_parts();
+//! assert_eq!(kind, DrinkKind::Coffee);
+//!
+//! let drink = Drink::from_parts(kind, payload).unwrap();
+//! assert_eq!(drink, Drink::Coffee("Espresso".to_owned()));
+//!
+//! // A kind that does not match the payload results into an error
+//! let payload = DrinkPayload::Coffee("Latte".to_owned());
+//! assert!(Drink::from_parts(DrinkKind::Tea, payload).is_err());
+//! ```
+//!
+//! ### Payload structs
+//!
+//! With `payload_structs` attribute every variant with data gets a standalone struct that holds the data of the variant,
+//! so it can be passed around on its own. Traits for the generated payload types can be derived with `payload_derive(..)`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded, Debug, PartialEq)]
+//! #[kinded(payload_structs, payload_derive(Debug, Clone, PartialEq))]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool },
+//! }
+//!
+//! let payload = TeaPayload { variety: "Green".to_owned(), caffeine: true };
+//! assert_eq!(TeaPayload::KIND, DrinkKind::Tea);
+//!
+//! let tea = Drink::from(payload.clone());
+//! assert_eq!(TeaPayload::try_from(tea), Ok(payload));
+//!
+//! // The original value is given back if the variant does not match
+//! assert_eq!(CoffeePayload::try_from(Drink::Mate), Err(Drink::Mate));
+//! ```
+//!
+//! Note: `payload_structs` is not supported for enums with generics.
+//! `payload_derive(..)` also applies to the `DrinkPayload` enum generated with `parts`.
+//!
+//! ### Comparing with kind
+//!
+//! The main enum and the kind type can be compared directly with each other:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! let drink = Drink::Coffee("Espresso".to_owned());
+//! assert!(drink == DrinkKind::Coffee);
+//! assert!(DrinkKind::Coffee == drink);
+//!
+//! let drinks = [Drink::Mate, Drink::Coffee("Latte".to_owned())];
+//! let mate_count = drinks.iter().filter(|&drink| DrinkKind::Mate == drink).count();
+//! assert_eq!(mate_count, 1);
+//! ```
+//!
+//! Since the kind type implements `PartialEq` for several types, comparing it with a value of an inferred type
+//! (e.g. `kind == "mate".parse().unwrap()` or `assert_eq!(kind, Default::default())`) fails with
+//! "type annotations needed". Annotate the type (e.g. `"mate".parse::<DrinkKind>()`), or opt out of the implementations
+//! with `skip_derive(CrossPartialEq)` to restore the inference.
+//!
+//! ### Main enum from kind
+//!
+//! Unit variants of the main enum can be constructed from the kind with `TryFrom`.
+//! Variants with data can't be constructed and result into `NonUnitKindError`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded, Debug, PartialEq)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(Drink::try_from(DrinkKind::Mate), Ok(Drink::Mate));
+//! assert!(Drink::try_from(DrinkKind::Coffee).is_err());
+//! ```
+//!
+//! If all the variants of the main enum are unit variants, the main enum also implements `FromStr`,
+//! which parses the same way as the kind type does:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded, Debug, PartialEq)]
+//! #[kinded(display = "snake_case")]
+//! enum Status {
+//!     Active,
+//!     OnHold,
+//! }
+//!
+//! assert_eq!("on_hold".parse::<Status>().unwrap(), Status::OnHold);
+//! ```
+//!
+//! `skip_derive(TryFrom)` and `skip_derive(MainFromStr)` opt out of these implementations, e.g. to provide
+//! your own ones or the ones derived by another crate. `skip_derive(FromStr)` opts out of `FromStr` for both
+//! the kind type and the main enum.
+//!
+//! ### Default values
+//!
+//! With `default_values` the kind type gets a `default_value()` method that constructs the
+//! corresponding variant of the main enum, filling all the fields with `Default::default()`.
+//! A variant can provide its own constructor with `#[kinded(default_with = path)]`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded, Debug, PartialEq)]
+//! #[kinded(default_values)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     #[kinded(default_with = orange_juice)]
+//!     Juice { fruit: String },
+//! }
+//!
+//! fn orange_juice() -> Drink {
+//!     Drink::Juice { fruit: "Orange".to_owned() }
+//! }
+//!
+//! assert_eq!(DrinkKind::Coffee.default_value(), Drink::Coffee(String::new()));
+//! assert_eq!(DrinkKind::Juice.default_value(), orange_juice());
+//! ```
+//!
+//! ### Visitor
+//!
+//! With `visitor` attribute a `DrinkVisitor` trait is generated with a `visit_<variant>()` method per
+//! variant, which receives references to the fields. `Drink::accept()` dispatches to the matching method:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(visitor)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool },
+//! }
+//!
+//! struct Describe;
+//!
+//! impl DrinkVisitor for Describe {
+//!     type Output = String;
+//!
+//!     fn visit_mate(&mut self) -> String {
+//!         "mate".to_owned()
+//!     }
+//!
+//!     fn visit_coffee(&mut self, field_0: &String) -> String {
+//!         format!("coffee {field_0}")
+//!     }
+//!
+//!     fn visit_tea(&mut self, variety: &String, caffeine: &bool) -> String {
+//!         format!("tea {variety}, caffeine: {caffeine}")
+//!     }
+//! }
+//!
+//! assert_eq!(Drink::Coffee("Espresso".to_owned()).accept(&mut Describe), "coffee Espresso");
+//! ```
+//!
+//! With `visitor(fallback)` all the `visit_<variant>()` methods get default implementations
+//! that call the required `visit_any(kind)` method, so only the interesting variants have to be handled.
+//!
+//! ### Marker types
+//!
+//! With `markers` attribute a zero-sized marker type is generated per variant in a module
+//! named after the kind type (e.g. `drink_kind::Coffee`). Markers implement `KindMarker` trait with `const KIND`.
+//! `Drink::try_into_typed::<M>()` returns `Typed<Drink, M>`, which guarantees on the type level
+//! that the value has the variant of the marker and gives infallible access to the payload:
+//!
+//! ```
+//! use kinded::{Kinded, Typed};
+//!
+//! #[derive(Kinded, Debug)]
+//! #[kinded(markers)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool },
+//! }
+//!
+//! fn brew(coffee: Typed<Drink, drink_kind::Coffee>) -> String {
+//!     coffee.into_payload()
+//! }
+//!
+//! let coffee = Drink::Coffee("Espresso".to_owned());
+//! let typed = coffee.try_into_typed::<drink_kind::Coffee>().unwrap();
+//! assert_eq!(brew(typed), "Espresso");
+//!
+//! assert!(Drink::Mate.try_into_typed::<drink_kind::Coffee>().is_err());
+//! ```
+//!
+//! The payload is the field itself for single field variants, a tuple of the fields for variants
+//! with multiple fields and `()` for unit variants.
+//!
+//! ### Keeping fields in the kind
+//!
+//! Fields marked with `#[kinded(keep)]` are kept in the kind variant, and `kind()` copies them:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(skip_derive(Kind, FromStr))]
+//! enum Event {
+//!     UserCreated {
+//!         #[kinded(keep)]
+//!         tenant_id: u32,
+//!         name: String,
+//!     },
+//!     Ping,
+//! }
+//!
+//! let event = Event::UserCreated { tenant_id: 7, name: "Alice".to_owned() };
+//! assert_eq!(event.kind(), EventKind::UserCreated { tenant_id: 7 });
+//! ```
+//!
+//! Kept fields must implement the traits derived for the kind type (`Copy`, `Debug`, `PartialEq` and `Eq` by default).
+//! Such kind type can't list all its values, so `all()`, `FromStr` and the `Kind` trait can't be generated,
+//! and neither can the `Kinded` trait that requires it. This has to be acknowledged with `skip_derive(Kind, FromStr)`,
+//! otherwise `keep` is a compile error. `kind()`, `From` and the other generated methods are still available.
+//! `keep` can't be used on enums with generics and together with `markers` or `payload_structs`.
+//!
+//! ### Kind computed from payload
+//!
+//! A variant can represent several kinds depending on its data. `#[kinded(kind_with = path::to_fn)]` computes
+//! the kind with a function that receives references to the fields of the variant. Such variant has no kind
+//! variant of its own, extra kind variants can be declared with `extra_kinds(...)`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(extra_kinds(Int, Float))]
+//! enum Value {
+//!     Null,
+//!     #[kinded(kind_with = number_kind)]
+//!     Number(f64),
+//! }
+//!
+//! fn number_kind(n: &f64) -> ValueKind {
+//!     if n.fract() == 0.0 { ValueKind::Int } else { ValueKind::Float }
+//! }
+//!
+//! assert_eq!(Value::Number(1.0).kind(), ValueKind::Int);
+//! assert_eq!(Value::Number(1.5).kind(), ValueKind::Float);
+//! assert_eq!(ValueKind::all(), [ValueKind::Null, ValueKind::Int, ValueKind::Float]);
+//! ```
+//!
+//! When `kind_with` is used, `kind()` is not `const`.
+//!
+//! ### Nested kinds
+//!
+//! A variant whose single field implements `KindedPath` can be marked with `#[kinded(nested)]`.
+//! Then `kind_path()` returns a generated `EventKindPath` enum, which includes the kind path of the nested value.
+//! `EventKindPath::all()` returns all the paths, and `Display` joins the names with `.`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case")]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case")]
+//! enum Event {
+//!     Ping,
+//!     #[kinded(nested)]
+//!     Drink(Drink),
+//! }
+//!
+//! let event = Event::Drink(Drink::Coffee("Latte".to_owned()));
+//! assert_eq!(event.kind_path(), EventKindPath::Drink(DrinkKind::Coffee));
+//! assert_eq!(event.kind_path().to_string(), "drink.coffee");
+//! assert_eq!(
+//!     EventKindPath::all(),
+//!     [EventKindPath::Ping, EventKindPath::Drink(DrinkKind::Mate), EventKindPath::Drink(DrinkKind::Coffee)]
+//! );
+//! ```
+//!
+//! `#[derive(Kinded)]` implements `KindedPath` for every enum: the kind type is the kind path of an enum
+//! without nested variants, so the paths nest to any depth, e.g. `LogKindPath::Event(EventKindPath::Drink(DrinkKind::Coffee))`
+//! is displayed as `event.drink.coffee`. `KindedPath` is also implemented for the built-in kinds, references and smart pointers.
+//!
+//! `Display` of the kind path requires the nested kind paths to implement `Display`, so if it's skipped
+//! on the nested enum, it must be skipped on the outer one too.
+//! Unlike `Kind::all()`, `all()` returns a `Vec`, since the nested paths can't be listed in a const context.
+//!
+//! ### Flattened kinds
+//!
+//! A variant that wraps another enum can be marked with `#[kinded(flatten)]`, so the kind type directly contains
+//! the kinds of the inner enum, prefixed with the variant name. The inner enum must derive `Kinded` with `export` attribute,
+//! which generates a macro that provides its kinds to the outer enum:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! mod drinks {
+//!     use kinded::Kinded;
+//!
+//!     #[derive(Kinded)]
+//!     #[kinded(export)]
+//!     pub enum Drink {
+//!         Mate,
+//!         Coffee(String),
+//!     }
+//! }
+//!
+//! use drinks::Drink;
+//!
+//! #[derive(Kinded)]
+//! enum Event {
+//!     Ping,
+//!     #[kinded(flatten)]
+//!     Drink(Drink),
+//! }
+//!
+//! assert_eq!(Event::Drink(Drink::Mate).kind(), EventKind::DrinkMate);
+//! assert_eq!(EventKind::all(), [EventKind::Ping, EventKind::DrinkMate, EventKind::DrinkCoffee]);
+//! ```
+//!
+//! The macro of a `pub` enum is exported from its crate (hidden from the docs), so the enum can be flattened in other crates too.
+//! Otherwise the macro has the visibility of the enum.
+//! When flatten is used, `kind()` is not `const`.
+//!
+//! ### Multiple kinds
+//!
+//! Additional kind types can be defined with more `#[kinded(..)]` attributes that specify `kind = ..` and `method = ..`.
+//! Each one gets its own enum and accessor method, and can be configured with `derive(..)`, `skip_derive(..)`,
+//! `display = ".."` and `attrs(..)`. By default an additional kind has the same variants as the main enum,
+//! `group(..)` maps the variants onto coarser kinds instead:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case")]
+//! #[kinded(kind = DrinkCategory, method = category, display = "UPPERCASE", group(Hot = [Coffee, Tea], Cold = [Mate]))]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String },
+//! }
+//!
+//! let drink = Drink::Coffee("Espresso".to_owned());
+//! assert_eq!(drink.kind(), DrinkKind::Coffee);
+//! assert_eq!(drink.category(), DrinkCategory::Hot);
+//! assert_eq!(DrinkCategory::Hot.to_string(), "HOT");
+//! assert_eq!(DrinkCategory::all(), [DrinkCategory::Hot, DrinkCategory::Cold]);
+//! ```
+//!
+//! The `Kinded` trait and the other features are tied to the primary kind, configured by the attribute without `method`.
+//!
+//! ### Existing kind type
+//!
+//! Instead of generating a new kind type, the main enum can be mapped onto an existing fieldless enum with
+//! `kind_of = path::to::Type`. Variants are matched by name, or mapped explicitly with `#[kinded(as = ..)]`,
+//! and a variant without a counterpart is a compile error:
+//!
+//! ```
+//! use kinded::{Kind, Kinded};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum EventType {
+//!     Created,
+//!     Deleted,
+//! }
+//!
+//! impl Kind for EventType {
+//!     fn all() -> &'static [EventType] {
+//!         &[EventType::Created, EventType::Deleted]
+//!     }
+//! }
+//!
+//! #[derive(Kinded)]
+//! #[kinded(kind_of = EventType)]
+//! enum Event {
+//!     Created { id: u32 },
+//!     #[kinded(as = Deleted)]
+//!     Removed(u32),
+//! }
+//!
+//! assert_eq!(Event::Removed(1).kind(), EventType::Deleted);
+//! assert_eq!(EventType::from(&Event::Created { id: 1 }), EventType::Created);
+//! ```
+//!
+//! Only `kind()`, `Kinded`, `From` and `PartialEq` are implemented for it, attributes that configure the generated
+//! kind type (e.g. `display` or `derive`) are not allowed. The `Kinded` trait requires the existing type to implement `Kind`,
+//! so derive it with `#[derive(kinded::Kind)]` where the type is defined (see [Deriving Kind on fieldless enums](#deriving-kind-on-fieldless-enums)).
+//! A type of another crate that doesn't implement `Kind` can't get it because of the orphan rule,
+//! so skip the `Kinded` trait with `skip_derive(Kinded)` to use such type.
+//! Several variants can be mapped onto the same kind, except with `markers`, which require a distinct kind per variant.
+//!
+//! ### Subsets
+//!
+//! `subset(HotDrinkKind = [Coffee, Tea])` generates a kind type with only the listed kind variants. It has the same
+//! derives, `Display` and `FromStr` configuration as the main kind, converts into it with `From` and back with `TryFrom`,
+//! which fails with `NotInSubsetError`. The main enum gets a method named after the subset without the enum name:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(subset(HotDrinkKind = [Coffee, Tea]))]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String },
+//! }
+//!
+//! assert_eq!(Drink::Coffee("Latte".to_owned()).hot_kind(), Some(HotDrinkKind::Coffee));
+//! assert_eq!(Drink::Mate.hot_kind(), None);
+//! assert_eq!(DrinkKind::from(HotDrinkKind::Tea), DrinkKind::Tea);
+//! assert!(HotDrinkKind::try_from(DrinkKind::Mate).is_err());
+//! ```
+//!
+//! Only the whole words of the enum name are omitted, so `Drinkable` gives `drinkable()`. When the name can't be
+//! derived this way, e.g. for a subset named `Drink` or `KindDrink`, the method has to be specified:
+//! `subset(HotDrinks(method = hot) = [Coffee, Tea])`.
+//!
+//! ### Categories
+//!
+//! Variants can be tagged with `#[kinded(category = "...")]`, a variant may belong to several categories.
+//! It generates `DrinkKindCategory` enum with a variant per category, `categories()` and `is_in()` on the kind,
+//! and `members()` on the category:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Drink {
+//!     #[kinded(category = "cold")]
+//!     Mate,
+//!     #[kinded(category = "hot", category = "caffeinated")]
+//!     Coffee(String),
+//!     #[kinded(category = "hot")]
+//!     Tea { variety: String },
+//! }
+//!
+//! assert_eq!(DrinkKind::Coffee.categories(), [DrinkKindCategory::Hot, DrinkKindCategory::Caffeinated]);
+//! assert_eq!(DrinkKindCategory::Hot.members(), [DrinkKind::Coffee, DrinkKind::Tea]);
+//! assert!(DrinkKind::Mate.is_in(DrinkKindCategory::Cold));
+//! assert_eq!(DrinkKindCategory::Caffeinated.to_string(), "caffeinated");
+//! ```
+//!
+//! ### Relations between kinds
+//!
+//! Variants can declare the kinds they imply with `#[kinded(implies(...))]` and the kinds they conflict with
+//! with `#[kinded(conflicts(...))]`. The kind gets `implies()` (transitively) and `conflicts()` (in both directions),
+//! `closure(kinds)` that adds the implied kinds to a set of kinds, and `validate(kinds)` that reports a conflict
+//! with `KindConflictError`. Unknown kinds, cyclic implications and kinds that imply conflicting kinds are
+//! compile errors:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Permission {
+//!     Read { path: String },
+//!     Write { path: String },
+//!     #[kinded(implies(Read, Write))]
+//!     Admin,
+//!     #[kinded(implies(Read), conflicts(Write))]
+//!     ReadOnly,
+//! }
+//!
+//! assert_eq!(PermissionKind::Admin.implies(), [PermissionKind::Read, PermissionKind::Write]);
+//! assert_eq!(PermissionKind::Write.conflicts(), [PermissionKind::ReadOnly]);
+//! assert_eq!(
+//!     PermissionKind::closure([PermissionKind::ReadOnly]),
+//!     [PermissionKind::Read, PermissionKind::ReadOnly]
+//! );
+//! assert!(PermissionKind::validate([PermissionKind::Admin, PermissionKind::ReadOnly]).is_err());
+//! ```
+//!
+//! ### Transitions
+//!
+//! Declare the allowed transitions between kinds with `transitions(..)` on the variants to treat the kind as a state machine:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Order {
+//!     #[kinded(transitions(Paid, Cancelled))]
+//!     Pending,
+//!     #[kinded(transitions(Shipped, Cancelled))]
+//!     Paid { amount: u32 },
+//!     #[kinded(transitions(Delivered))]
+//!     Shipped,
+//!     Delivered,
+//!     Cancelled,
+//! }
+//!
+//! assert!(OrderKind::Pending.can_transition_to(OrderKind::Paid));
+//! assert_eq!(OrderKind::Paid.successors(), [OrderKind::Shipped, OrderKind::Cancelled]);
+//!
+//! // Initial states can't be reached from other states, terminal states have no successors
+//! assert_eq!(OrderKind::initial_states(), [OrderKind::Pending]);
+//! assert_eq!(OrderKind::terminal_states(), [OrderKind::Delivered, OrderKind::Cancelled]);
+//!
+//! let paid = Order::Paid { amount: 10 };
+//! assert!(Order::Pending.check_transition(&paid).is_ok());
+//! assert_eq!(
+//!     Order::Delivered.check_transition(&paid).unwrap_err().to_string(),
+//!     "Transition from Delivered to Paid is not allowed"
+//! );
+//! ```
+//!
+//! ### Deriving Kind on fieldless enums
+//!
+//! An enum that has no data is already a kind. `#[derive(Kind)]` turns it into a kind type without generating
+//! a new one: it gets `all()`, `Display`, `FromStr` (see [FromStr trait](#fromstr-trait)) and the `Kind` trait implementation.
+//! `display`, `ignore_case` and `skip_derive` attributes on the enum and `rename` on the variants work the same way as with `Kinded`.
+//! The enum must derive `Debug`, `Clone`, `Copy`, `PartialEq` and `Eq`, which are required by `Kind` trait:
+//!
+//! ```
+//! use kinded::Kind;
+//!
+//! #[derive(Kind, Debug, Clone, Copy, PartialEq, Eq)]
+//! #[kinded(display = "snake_case")]
+//! enum Color {
+//!     Red,
+//!     DarkGreen,
+//! }
+//!
+//! assert_eq!(Color::all(), [Color::Red, Color::DarkGreen]);
+//! assert_eq!(Color::DarkGreen.to_string(), "dark_green");
+//! assert_eq!("dark-green".parse::<Color>().unwrap(), Color::DarkGreen);
+//! ```
+//!
+//! Deriving `Kind` on an enum with data is a compile error.
+//!
+//! ### Remote enums
+//!
+//! Enums defined in other crates can't be annotated, but their kinds can be derived from a copy of the definition,
+//! similar to serde's remote derive. `#[kinded(remote = path::to::Enum)]` generates the kind type (e.g. `IpAddrKind`)
+//! and an extension trait (e.g. `IpAddrKinded`) that provides `kind()` for the remote enum.
+//! The variants and the fields of the definition are checked against the remote enum, so any mismatch is a compile error:
+//!
+//! ```
+//! use kinded::Kinded;
+//! use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+//!
+//! #[derive(Kinded)]
+//! #[kinded(remote = std::net::IpAddr)]
+//! enum IpAddrDef {
+//!     V4(Ipv4Addr),
+//!     V6(Ipv6Addr),
+//! }
+//!
+//! let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+//! assert_eq!(ip.kind(), IpAddrKind::V4);
+//! assert_eq!(IpAddrKind::all(), [IpAddrKind::V4, IpAddrKind::V6]);
+//! ```
+//!
+//! A `#[non_exhaustive]` remote enum can't be matched exhaustively, so its definition has to be `#[non_exhaustive]` too
+//! and `other = Unknown` adds a kind variant for the variants that are missing from the definition:
+//!
+//! ```rs
+//! use kinded::Kinded;
+//! use std::sync::atomic::Ordering;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(remote = std::sync::atomic::Ordering, other = Unknown)]
+//! #[non_exhaustive]
+//! enum OrderingDef {
+//!     Relaxed,
+//!     Release,
+//!     Acquire,
+//!     AcqRel,
+//!     SeqCst,
+//! }
+//!
+//! assert_eq!(Ordering::SeqCst.kind(), OrderingKind::SeqCst);
+//! assert_eq!(OrderingKind::all().last(), Some(&OrderingKind::Unknown));
+//! ```
+//!
+//! Only the attributes that configure the kind type are supported in this mode, since no methods can be added to the remote enum.
+//!
+//! ### Built-in kinds
+//!
+//! `Kinded` is implemented for common enums of the standard library, so generic code over `Kinded` can handle them too:
+//!
+//! | Type | Kind |
+//! |------|------|
+//! | `Option<T>` | `OptionKind` (`Some`, `None`) |
+//! | `Result<T, E>` | `ResultKind` (`Ok`, `Err`) |
+//! | `core::cmp::Ordering` | `OrderingKind` (`Less`, `Equal`, `Greater`) |
+//! | `core::ops::Bound<T>` | `BoundKind` (`Included`, `Excluded`, `Unbounded`) |
+//! | `Cow<'_, B>` | `CowKind` (`Borrowed`, `Owned`) |
+//! | `serde_json::Value` (feature `serde_json`) | `JsonValueKind` (`Null`, `Bool`, `Number`, `String`, `Array`, `Object`) |
+//! | `std::io::Error` (feature `std`) | `std::io::ErrorKind` |
+//!
+//! `std::io::ErrorKind` is `#[non_exhaustive]` and keeps growing, so its `Kind::all()` lists only the kinds
+//! that are stable in Rust 1.85. `Error::kind()` can still return kinds missing from `all()`, e.g. the ones
+//! stabilized later and the unstable ones that `std` uses internally, so don't rely on `all()` containing every kind
+//! of an `io::Error`.
+//!
+//! ```
+//! use kinded::{Kinded, OptionKind, ResultKind};
+//!
+//! fn kind_of<T: Kinded>(value: &T) -> T::Kind {
+//!     value.kind()
+//! }
+//!
+//! assert_eq!(kind_of(&Some(1)), OptionKind::Some);
+//! assert_eq!(kind_of(&"x".parse::<u8>()), ResultKind::Err);
+//! ```
+//!
+//! ### Kinded references, pointers and kinds
+//!
+//! `Kinded` is implemented for `&T`, `&mut T`, `Box<T>`, `Rc<T>` and `Arc<T>` when `T` implements it,
+//! and every generated kind is the kind of itself (`Kinded<Kind = Self>`), so generic code accepts all of them:
+//!
+//! ```
+//! use kinded::Kinded;
+//! use std::sync::Arc;
+//!
+//! #[derive(Kinded)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! fn kind_of<T: Kinded>(value: T) -> T::Kind {
+//!     value.kind()
+//! }
+//!
+//! let coffee = Drink::Coffee("espresso".to_string());
+//! assert_eq!(kind_of(&coffee), DrinkKind::Coffee);
+//! assert_eq!(kind_of(Arc::new(Drink::Mate)), DrinkKind::Mate);
+//! assert_eq!(kind_of(DrinkKind::Mate), DrinkKind::Mate);
+//! ```
+//!
+//! ### Structs delegating to an enum field
+//!
+//! `Kinded` can be derived on a struct that has exactly one field marked with `#[kinded(delegate)]`.
+//! The struct gets `kind()` and the `Kinded` implementation that return the kind of that field:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum EventBody {
+//!     Created { name: String },
+//!     Deleted,
+//! }
+//!
+//! #[derive(Kinded)]
+//! struct Envelope {
+//!     id: u64,
+//!     #[kinded(delegate)]
+//!     body: EventBody,
+//! }
+//!
+//! let envelope = Envelope { id: 1, body: EventBody::Deleted };
+//! assert_eq!(envelope.kind(), EventBodyKind::Deleted);
+//! ```
+//!
+//! ## no_std
+//!
+//! `kinded` is `#![no_std]`, but it requires `alloc`: the generated code returns `Vec`s (e.g. `all_kind_paths()`)
+//! and `Kinded` is implemented for `Box<T>`, `Rc<T>` and `Arc<T>`. `Arc<T>` is covered only on the targets
+//! that support atomic pointers. The `std` feature is needed only for the `std::io` kinds.
+//!
 //! ## A note about the war in Ukraine 🇺🇦
 //!
 //! Today I live in Berlin, I have the luxury to live a physically safe life.
@@ -325,9 +1129,50 @@
 
 #![no_std]
 
+// The built-in kinds are derived with the macros that refer to the crate as `::kinded`
+extern crate self as kinded;
+
 mod errors;
+mod kinds;
 mod traits;
+mod typed;
+
+pub use errors::{
+    KindConflictError, KindMismatchError, NonUnitKindError, NotInSubsetError, ParseKindError,
+    TransitionError,
+};
+pub use kinded_macros::{Kind, Kinded};
+#[cfg(feature = "serde_json")]
+pub use kinds::JsonValueKind;
+pub use kinds::{BoundKind, CowKind, OptionKind, OrderingKind, ResultKind};
+pub use traits::{Kind, KindMarker, Kinded, KindedPath, VariantPayload};
+pub use typed::Typed;
+
+/// Re-exports used by the generated code. Not a part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    extern crate alloc;
+
+    pub use alloc::vec::Vec;
+    pub use kinded_macros::__kinded_flatten as flatten;
+
+    /// Kind path of a nested value, displayed as a part of the outer kind path.
+    #[diagnostic::on_unimplemented(
+        message = "the kind path of the nested value `{Self}` doesn't implement `Display`",
+        label = "the nested kind path must implement `Display`",
+        note = "don't skip `Display` of the nested enum, or skip it with `skip_derive(Display)` on the outer enum as well"
+    )]
+    pub trait NestedDisplay: core::fmt::Display {}
+
+    impl<T: core::fmt::Display> NestedDisplay for T {}
+
+    pub fn nested_display<T: NestedDisplay>(nested: &T) -> &T {
+        nested
+    }
 
-pub use errors::ParseKindError;
-pub use kinded_macros::Kinded;
-pub use traits::{Kind, Kinded};
+    /// Position of the kind variant in the definition.
+    /// Implemented by kinds of enums with `export` attribute to map the kinds of flattened variants.
+    pub trait KindIndex {
+        fn kind_index(&self) -> usize;
+    }
+}
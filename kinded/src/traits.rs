@@ -1,4 +1,12 @@
+// The crate requires `alloc` (see "no_std" in the crate docs), so no feature gates the smart pointers
+extern crate alloc;
+
 use ::core::fmt::Debug;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 /// A trait that can be implemented by a main enum type.
 /// Typically should be derived with `#[derive(kinded::Kinded)]`.
@@ -9,7 +17,160 @@ pub trait Kinded {
     fn kind(&self) -> Self::Kind;
 }
 
+impl<T: Kinded + ?Sized> Kinded for &T {
+    type Kind = T::Kind;
+
+    fn kind(&self) -> T::Kind {
+        (**self).kind()
+    }
+}
+
+impl<T: Kinded + ?Sized> Kinded for &mut T {
+    type Kind = T::Kind;
+
+    fn kind(&self) -> T::Kind {
+        (**self).kind()
+    }
+}
+
+impl<T: Kinded + ?Sized> Kinded for Box<T> {
+    type Kind = T::Kind;
+
+    fn kind(&self) -> T::Kind {
+        (**self).kind()
+    }
+}
+
+impl<T: Kinded + ?Sized> Kinded for Rc<T> {
+    type Kind = T::Kind;
+
+    fn kind(&self) -> T::Kind {
+        (**self).kind()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: Kinded + ?Sized> Kinded for Arc<T> {
+    type Kind = T::Kind;
+
+    fn kind(&self) -> T::Kind {
+        (**self).kind()
+    }
+}
+
+/// Kind of a value together with the kinds of its nested values, e.g. `EventKindPath::Drink(DrinkKind::Coffee)`.
+/// Implemented with `#[derive(Kinded)]`: the kind path type is generated for enums with `#[kinded(nested)]`
+/// variants, the other enums use their kind type as the kind path.
+pub trait KindedPath: Kinded {
+    type KindPath: PartialEq + Eq + Debug + Clone + Copy;
+
+    /// Get the kind path of the value.
+    fn kind_path(&self) -> Self::KindPath;
+
+    /// Return all possible kind paths.
+    ///
+    /// The paths are built from the paths of the nested values, which can't be listed in a const context,
+    /// so unlike [`Kind::all()`] they are collected into a `Vec`.
+    fn all_kind_paths() -> Vec<Self::KindPath>;
+}
+
+impl<T: KindedPath + ?Sized> KindedPath for &T {
+    type KindPath = T::KindPath;
+
+    fn kind_path(&self) -> T::KindPath {
+        (**self).kind_path()
+    }
+
+    fn all_kind_paths() -> Vec<T::KindPath> {
+        T::all_kind_paths()
+    }
+}
+
+impl<T: KindedPath + ?Sized> KindedPath for &mut T {
+    type KindPath = T::KindPath;
+
+    fn kind_path(&self) -> T::KindPath {
+        (**self).kind_path()
+    }
+
+    fn all_kind_paths() -> Vec<T::KindPath> {
+        T::all_kind_paths()
+    }
+}
+
+impl<T: KindedPath + ?Sized> KindedPath for Box<T> {
+    type KindPath = T::KindPath;
+
+    fn kind_path(&self) -> T::KindPath {
+        (**self).kind_path()
+    }
+
+    fn all_kind_paths() -> Vec<T::KindPath> {
+        T::all_kind_paths()
+    }
+}
+
+impl<T: KindedPath + ?Sized> KindedPath for Rc<T> {
+    type KindPath = T::KindPath;
+
+    fn kind_path(&self) -> T::KindPath {
+        (**self).kind_path()
+    }
+
+    fn all_kind_paths() -> Vec<T::KindPath> {
+        T::all_kind_paths()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: KindedPath + ?Sized> KindedPath for Arc<T> {
+    type KindPath = T::KindPath;
+
+    fn kind_path(&self) -> T::KindPath {
+        (**self).kind_path()
+    }
+
+    fn all_kind_paths() -> Vec<T::KindPath> {
+        T::all_kind_paths()
+    }
+}
+
 pub trait Kind: PartialEq + Eq + Debug + Clone + Copy {
     /// Return a slice with all possible kind variants.
     fn all() -> &'static [Self];
 }
+
+/// A zero-sized type that represents a single kind variant on the type level.
+/// Typically generated with `#[kinded(markers)]`.
+pub trait KindMarker {
+    type Kind: PartialEq + Eq + Debug + Clone + Copy + Kind;
+
+    /// The kind variant the marker represents.
+    const KIND: Self::Kind;
+}
+
+/// Access to the payload of the variant represented by marker `M`.
+/// Typically generated with `#[kinded(markers)]`.
+///
+/// The payload is the field of a single field variant, a tuple of the fields when the variant has
+/// multiple fields and `()` for unit variants.
+pub trait VariantPayload<M: KindMarker>: Kinded<Kind = M::Kind> + Sized {
+    type Payload;
+
+    type PayloadRef<'a>
+    where
+        Self: 'a;
+
+    type PayloadMut<'a>
+    where
+        Self: 'a;
+
+    /// Get references to the payload, or `None` if the value has a different variant.
+    fn variant_payload(&self) -> Option<Self::PayloadRef<'_>>;
+
+    /// Get mutable references to the payload, or `None` if the value has a different variant.
+    fn variant_payload_mut(&mut self) -> Option<Self::PayloadMut<'_>>;
+
+    /// Take the payload, or return the value back if it has a different variant.
+    fn into_variant_payload(self) -> Result<Self::Payload, Self>;
+}
@@ -0,0 +1,80 @@
+use crate::traits::{KindMarker, Kinded, VariantPayload};
+use ::core::marker::PhantomData;
+use ::core::ops::Deref;
+
+/// A value of the main enum which is guaranteed to have the variant represented by marker `M`.
+///
+/// It can be created with `Typed::new()` or with `try_into_typed()` generated by `#[kinded(markers)]`.
+/// Since the variant is known, the payload can be accessed without matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Typed<T, M> {
+    value: T,
+    marker: PhantomData<M>,
+}
+
+impl<T, M> Typed<T, M>
+where
+    T: Kinded,
+    M: KindMarker<Kind = T::Kind>,
+{
+    /// Wrap the value, or return it back if it does not have the variant of marker `M`.
+    pub fn new(value: T) -> Result<Self, T> {
+        if value.kind() == M::KIND {
+            Ok(Self {
+                value,
+                marker: PhantomData,
+            })
+        } else {
+            Err(value)
+        }
+    }
+
+    /// Get the kind of the value. It is always the kind represented by marker `M`.
+    pub fn kind(&self) -> T::Kind {
+        M::KIND
+    }
+
+    /// Unwrap the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, M> Typed<T, M>
+where
+    T: VariantPayload<M>,
+    M: KindMarker,
+{
+    /// Get references to the payload of the variant.
+    pub fn payload(&self) -> T::PayloadRef<'_> {
+        match self.value.variant_payload() {
+            Some(payload) => payload,
+            None => unreachable!("Typed always holds the variant of its marker"),
+        }
+    }
+
+    /// Get mutable references to the payload of the variant.
+    /// Unlike the value itself, the payload can be mutated, since it can't change the variant.
+    pub fn payload_mut(&mut self) -> T::PayloadMut<'_> {
+        match self.value.variant_payload_mut() {
+            Some(payload) => payload,
+            None => unreachable!("Typed always holds the variant of its marker"),
+        }
+    }
+
+    /// Take the payload of the variant.
+    pub fn into_payload(self) -> T::Payload {
+        match self.value.into_variant_payload() {
+            Ok(payload) => payload,
+            Err(_) => unreachable!("Typed always holds the variant of its marker"),
+        }
+    }
+}
+
+impl<T, M> Deref for Typed<T, M> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
@@ -0,0 +1,134 @@
+//! Cooperation between the derive of an enum with flattened variants and the derives of the inner enums.
+//!
+//! A derive macro can't see the variants of other enums, so for every flattened variant the derive
+//! invokes the macro exported by the inner enum (see `export` attribute), which calls `__kinded_flatten`
+//! back with the kinds of the inner enum:
+//!
+//! ```text
+//! inner::Drink! { variant = Drink; resolved = [..]; input = { enum Event { .. } } }
+//!
+//! ::kinded::__private::flatten! {
+//!     variants = [Mate, Coffee];
+//!     variant = Drink; resolved = [..]; input = { enum Event { .. } }
+//! }
+//! ```
+//!
+//! Once the kinds of all the flattened variants are resolved, the code is generated as usual.
+
+use crate::generate::generate;
+use crate::parse::{flatten_macro_path, parse_derive_input, resolve_flatten};
+use proc_macro2::{Ident, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    DeriveInput, Token, braced, bracketed,
+    parse::{Parse, ParseStream},
+};
+
+/// Kinds of the inner enum of a flattened variant, e.g. `Drink: [Mate, Coffee]`.
+pub struct Resolved {
+    variant: Ident,
+    variants: Vec<Ident>,
+}
+
+/// Input of `__kinded_flatten` macro.
+pub struct FlattenInput {
+    resolved: Vec<Resolved>,
+    input: DeriveInput,
+}
+
+/// Generate the code for the enum, or invoke the macro of the next flattened enum to resolve its kinds.
+pub fn expand(input: DeriveInput, resolved: Vec<Resolved>) -> Result<TokenStream, syn::Error> {
+    let mut meta = parse_derive_input(input.clone())?;
+    for Resolved { variant, variants } in &resolved {
+        resolve_flatten(&mut meta, variant, variants.clone())?;
+    }
+
+    let Some(variant) = meta.unresolved_flatten() else {
+        return Ok(generate(meta));
+    };
+    let variant_name = &variant.ident;
+    let macro_path = flatten_macro_path(&variant.fields[0].ty)
+        .expect("flatten_macro_path is validated when the input is parsed");
+
+    Ok(quote!(
+        #macro_path! {                                                         // inner::Drink! {
+            variant = #variant_name;                                           //     variant = Drink;
+            resolved = [#(#resolved),*];                                       //     resolved = [Snack: [Cookie]];
+            input = { #input }                                                 //     input = { enum Event { .. } }
+        }                                                                      // }
+    ))
+}
+
+impl FlattenInput {
+    pub fn expand(self) -> Result<TokenStream, syn::Error> {
+        expand(self.input, self.resolved)
+    }
+}
+
+impl Parse for FlattenInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        parse_keyword(input, "variants")?;
+        let variants = parse_idents(input)?;
+        let _: Token![;] = input.parse()?;
+
+        parse_keyword(input, "variant")?;
+        let variant: Ident = input.parse()?;
+        let _: Token![;] = input.parse()?;
+
+        parse_keyword(input, "resolved")?;
+        let resolved_input;
+        bracketed!(resolved_input in input);
+        let mut resolved: Vec<Resolved> = resolved_input
+            .parse_terminated(Resolved::parse, Token![,])?
+            .into_iter()
+            .collect();
+        let _: Token![;] = input.parse()?;
+
+        parse_keyword(input, "input")?;
+        let derive_input;
+        braced!(derive_input in input);
+        let derive_input: DeriveInput = derive_input.parse()?;
+
+        resolved.push(Resolved { variant, variants });
+
+        Ok(Self {
+            resolved,
+            input: derive_input,
+        })
+    }
+}
+
+impl Parse for Resolved {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let variant: Ident = input.parse()?;
+        let _: Token![:] = input.parse()?;
+        let variants = parse_idents(input)?;
+        Ok(Self { variant, variants })
+    }
+}
+
+impl ToTokens for Resolved {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { variant, variants } = self;
+        tokens.extend(quote!(#variant: [#(#variants),*]));
+    }
+}
+
+/// Parse `name =`.
+fn parse_keyword(input: ParseStream, keyword: &str) -> syn::Result<()> {
+    let ident: Ident = input.parse()?;
+    if ident != keyword {
+        let msg = format!("Expected `{keyword}`, got `{ident}`");
+        return Err(syn::Error::new(ident.span(), msg));
+    }
+    let _: Token![=] = input.parse()?;
+    Ok(())
+}
+
+/// Parse `[A, B, C]`.
+fn parse_idents(input: ParseStream) -> syn::Result<Vec<Ident>> {
+    let content;
+    bracketed!(content in input);
+    let idents = content.parse_terminated(Ident::parse, Token![,])?;
+    Ok(idents.into_iter().collect())
+}
@@ -0,0 +1,104 @@
+use crate::models::{DisplayCase, Meta, Trait};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::LitStr;
+
+pub fn gen_categories(meta: &Meta) -> TokenStream {
+    let categories = meta.categories();
+    if categories.is_empty() {
+        return quote!();
+    }
+    let vis = &meta.vis;
+    let kind_name = meta.kind_name();
+    let category_name = meta.category_name();
+    let category_variants: Vec<Ident> = categories.iter().map(|c| category_variant(c)).collect();
+
+    let members_branches = categories.iter().map(|category| {
+        let category_variant = category_variant(category);
+        let member_names = meta
+            .variants
+            .iter()
+            .filter(|variant| {
+                variant
+                    .categories
+                    .iter()
+                    .any(|c| c.value() == category.value())
+            })
+            .map(|variant| &variant.ident);
+        quote!(#category_name::#category_variant => &[#(#kind_name::#member_names),*])
+    });
+
+    let categories_branches = meta.kind_variants().map(|variant| {
+        let variant_name = &variant.ident;
+        let variant_categories = variant.categories.iter().map(category_variant);
+        quote!(#kind_name::#variant_name => &[#(#category_name::#variant_categories),*])
+    });
+
+    let is_in_patterns = meta.variants.iter().flat_map(|variant| {
+        let variant_name = &variant.ident;
+        let category_name = &category_name;
+        let kind_name = &kind_name;
+        variant.categories.iter().map(move |category| {
+            let category_variant = category_variant(category);
+            quote!((#kind_name::#variant_name, #category_name::#category_variant))
+        })
+    });
+
+    let impl_display_trait = if meta.kinded_attrs.should_skip(Trait::Display) {
+        quote!()
+    } else {
+        quote!(
+            impl ::core::fmt::Display for #category_name {                     // impl Display for DrinkKindCategory {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {                                               //     match self {
+                        #(#category_name::#category_variants => write!(f, #categories)),* //     DrinkKindCategory::Hot => write!(f, "hot"),
+                    }                                                          //     }
+                }
+            }                                                                  // }
+        )
+    };
+
+    quote!(
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]                           // #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis enum #category_name {                                             // pub enum DrinkKindCategory {
+            #(#category_variants),*                                            //     Hot, Cold
+        }                                                                      // }
+
+        impl #category_name {                                                  // impl DrinkKindCategory {
+            pub fn all() -> &'static [#category_name] {                        //     pub fn all() -> &'static [DrinkKindCategory] {
+                &[#(#category_name::#category_variants),*]                     //         &[DrinkKindCategory::Hot, DrinkKindCategory::Cold]
+            }                                                                  //     }
+                                                                               //
+            pub const fn members(self) -> &'static [#kind_name] {              //     pub const fn members(self) -> &'static [DrinkKind] {
+                match self {                                                   //         match self {
+                    #(#members_branches),*                                     //             DrinkKindCategory::Hot => &[DrinkKind::Coffee, DrinkKind::Tea],
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl #kind_name {                                                      // impl DrinkKind {
+            pub const fn categories(self) -> &'static [#category_name] {       //     pub const fn categories(self) -> &'static [DrinkKindCategory] {
+                match self {                                                   //         match self {
+                    #(#categories_branches),*                                  //             DrinkKind::Coffee => &[DrinkKindCategory::Hot],
+                }                                                              //         }
+            }                                                                  //     }
+                                                                               //
+            pub const fn is_in(self, category: #category_name) -> bool {       //     pub const fn is_in(self, category: DrinkKindCategory) -> bool {
+                ::core::matches!(                                              //         matches!(
+                    (self, category),                                          //             (self, category),
+                    #(#is_in_patterns)|*                                       //             (DrinkKind::Coffee, DrinkKindCategory::Hot) | ..
+                )                                                              //         )
+            }                                                                  //     }
+        }                                                                      // }
+
+        #impl_display_trait
+    )
+}
+
+/// Get the variant of the category type for the category, e.g. `HotDrinks` for `"hot drinks"`.
+fn category_variant(category: &LitStr) -> Ident {
+    Ident::new(
+        &DisplayCase::Pascal.apply(&category.value()),
+        category.span(),
+    )
+}
@@ -0,0 +1,36 @@
+use crate::models::DelegateStruct;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_quote_spanned, spanned::Spanned};
+
+pub fn gen_delegate(delegate: &DelegateStruct) -> TokenStream {
+    let name = &delegate.ident;
+    let member = &delegate.member;
+    let ty = &delegate.ty;
+
+    // The delegate field is required to implement `Kinded`. The span of its type is used,
+    // so the compiler points to the field when it doesn't.
+    let mut generics = delegate.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote_spanned!(ty.span()=> #ty: ::kinded::Kinded));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let kind_type = quote_spanned!(ty.span()=> <#ty as ::kinded::Kinded>::Kind);
+
+    quote!(
+        impl #impl_generics #name #ty_generics #where_clause {                 // impl Envelope {
+            pub fn kind(&self) -> #kind_type {                                 //     pub fn kind(&self) -> <EventBody as Kinded>::Kind {
+                ::kinded::Kinded::kind(&self.#member)                          //         Kinded::kind(&self.body)
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl #impl_generics ::kinded::Kinded for #name #ty_generics #where_clause { // impl ::kinded::Kinded for Envelope {
+            type Kind = #kind_type;                                            //     type Kind = <EventBody as Kinded>::Kind;
+                                                                               //
+            fn kind(&self) -> #kind_type {                                     //     fn kind(&self) -> <EventBody as Kinded>::Kind {
+                ::kinded::Kinded::kind(&self.#member)                          //         Kinded::kind(&self.body)
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
@@ -0,0 +1,64 @@
+use crate::models::Meta;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use syn::{Ident, Visibility};
+
+/// Generate a macro that passes the kind variants to the enums that flatten this one.
+/// The macro is imported under the name of the enum, so it can be invoked with the same path as the type,
+/// e.g. `inner::Drink! { .. }`. The macro of a public enum is exported from the crate, so the enum can be
+/// flattened in other crates too.
+pub fn gen_export(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.export {
+        return quote!();
+    }
+    let name = &meta.ident;
+    let kind_name = meta.kind_name();
+    let kind_variant_names: Vec<&Ident> =
+        meta.kind_variants().map(|variant| &variant.ident).collect();
+    let indexes = 0..kind_variant_names.len();
+
+    // The macro of a public enum is exported, so it can be invoked from other crates. Exported macros are
+    // placed at the crate root, so the name has to be unique within the crate.
+    let (macro_name, macro_export, use_vis) = match meta.vis {
+        Visibility::Public(_) => {
+            let mut hasher = DefaultHasher::new();
+            name.to_string().hash(&mut hasher);
+            for variant_name in &kind_variant_names {
+                variant_name.to_string().hash(&mut hasher);
+            }
+            let macro_name = format_ident!("__kinded_flatten_{name}_{:016x}", hasher.finish());
+            (macro_name, quote!(#[macro_export]), quote!(pub))
+        }
+        ref vis => (
+            format_ident!("__kinded_flatten_{name}"),
+            quote!(),
+            quote!(#vis),
+        ),
+    };
+
+    quote!(
+        #[doc(hidden)]
+        #macro_export
+        macro_rules! #macro_name {                                             // macro_rules! __kinded_flatten_Drink {
+            ($($callback:tt)*) => {                                            //     ($($callback:tt)*) => {
+                ::kinded::__private::flatten! {                                //         ::kinded::__private::flatten! {
+                    variants = [#(#kind_variant_names),*];                     //             variants = [Mate, Coffee, Tea];
+                    $($callback)*                                              //             variant = Drink; resolved = [..]; input = { .. }
+                }                                                              //         }
+            };                                                                 //     };
+        }                                                                      // }
+
+        impl ::kinded::__private::KindIndex for #kind_name {                   // impl KindIndex for DrinkKind {
+            fn kind_index(&self) -> usize {                                    //     fn kind_index(&self) -> usize {
+                match self {                                                   //         match self {
+                    #(#kind_name::#kind_variant_names => #indexes),*           //             DrinkKind::Mate => 0, DrinkKind::Coffee => 1,
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        #use_vis use #macro_name as #name;                                     // pub use __kinded_flatten_Drink_.. as Drink;
+    )
+}
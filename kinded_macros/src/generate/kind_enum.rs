@@ -1,18 +1,23 @@
 use crate::models::{DisplayCase, Meta, Trait, Variant};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned};
 
 pub fn gen_kind_enum(meta: &Meta) -> TokenStream {
-    let kind_enum_definition = gen_definition(meta);
-    let impl_kind_trait = gen_impl_kind_trait(meta);
+    // The existing kind type is defined elsewhere, only the conversions from the main enum are implemented
+    if meta.kinded_attrs.kind_of.is_some() {
+        return gen_conversions(meta);
+    }
 
-    // Conditionally generate trait implementations based on skip_derive
-    let impl_from_traits = if meta.kinded_attrs.should_skip(Trait::From) {
+    let kind_enum_definition = gen_definition(meta);
+    let impl_kind_trait = if meta.kinded_attrs.should_skip(Trait::Kind) {
         quote!()
     } else {
-        gen_impl_from_traits(meta)
+        gen_impl_kind_trait(meta)
     };
 
+    let conversions = gen_conversions(meta);
+
     let impl_display_trait = if meta.kinded_attrs.should_skip(Trait::Display) {
         quote!()
     } else {
@@ -27,22 +32,75 @@ pub fn gen_kind_enum(meta: &Meta) -> TokenStream {
 
     quote!(
         #kind_enum_definition
-        #impl_from_traits
+        #conversions
         #impl_display_trait
         #impl_from_str_trait
         #impl_kind_trait
     )
 }
 
+/// Generate `all()`, `Display`, `FromStr` and `Kind` implementations for a fieldless enum
+/// that derives `Kind`, so it becomes a kind type itself.
+pub fn gen_kind_derive(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+    let fn_all = gen_fn_all(meta);
+    let impl_kind_trait = gen_impl_kind_trait(meta);
+
+    let impl_display_trait = if meta.kinded_attrs.should_skip(Trait::Display) {
+        quote!()
+    } else {
+        gen_impl_display_trait(meta)
+    };
+
+    let impl_from_str_trait = if meta.kinded_attrs.should_skip(Trait::FromStr) {
+        quote!()
+    } else {
+        gen_impl_from_str_trait(meta)
+    };
+
+    quote!(
+        impl #kind_name {                                                      // impl Color {
+            #fn_all                                                            //     pub fn all() -> &'static [Color] { .. }
+        }                                                                      // }
+
+        #impl_display_trait
+        #impl_from_str_trait
+        #impl_kind_trait
+    )
+}
+
+/// Generate `From` and cross `PartialEq` implementations between the main enum and the kind.
+fn gen_conversions(meta: &Meta) -> TokenStream {
+    // Conditionally generate trait implementations based on skip_derive
+    let impl_from_traits = if meta.kinded_attrs.should_skip(Trait::From) {
+        quote!()
+    } else {
+        gen_impl_from_traits(meta)
+    };
+
+    let impl_cross_partial_eq_traits = if meta.kinded_attrs.should_skip(Trait::CrossPartialEq) {
+        quote!()
+    } else {
+        gen_impl_cross_partial_eq_traits(meta)
+    };
+
+    quote!(
+        #impl_from_traits
+        #impl_cross_partial_eq_traits
+    )
+}
+
 fn gen_definition(meta: &Meta) -> TokenStream {
     let vis = &meta.vis;
     let kind_name = meta.kind_name();
     let traits = meta.derive_traits();
     let enum_attrs = meta.meta_attrs();
-    let variant_names: Vec<&Ident> = meta.variants.iter().map(|v| &v.ident).collect();
 
     let variants_with_attrs: Vec<TokenStream> =
-        meta.variants.iter().map(gen_variant_definition).collect();
+        meta.kind_variants().map(gen_variant_definition).collect();
+    let fn_all = gen_fn_all(meta);
+    let fn_predicates = gen_fn_predicates(meta);
+    let fn_default_value = gen_fn_default_value(meta);
 
     quote!(
         #[derive(#(#traits),*)]                                                // #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,10 +110,113 @@ fn gen_definition(meta: &Meta) -> TokenStream {
         }                                                                      // }
 
         impl #kind_name {                                                      // impl DrinkKind {
-            pub fn all() -> &'static [#kind_name] {                            //     pub fn all() -> &'static [DrinkKind] {
-                &[                                                             //         &[
-                    #(#kind_name::#variant_names),*                            //             DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea
-                ]                                                              //         ]
+            #fn_all                                                            //     pub fn all() -> &'static [DrinkKind] { .. }
+            #fn_predicates                                                     //     pub const fn is_coffee(&self) -> bool { .. }
+            #fn_default_value                                                  //     pub fn default_value(self) -> Drink { .. }
+        }                                                                      // }
+    )
+}
+
+fn gen_fn_all(meta: &Meta) -> TokenStream {
+    if meta.kinded_attrs.should_skip(Trait::Kind) {
+        return quote!();
+    }
+    let kind_name = meta.kind_name();
+    let variant_names: Vec<&Ident> = meta.kind_variants().map(|v| &v.ident).collect();
+
+    quote!(
+        pub fn all() -> &'static [#kind_name] {                                // pub fn all() -> &'static [DrinkKind] {
+            &[                                                                 //     &[
+                #(#kind_name::#variant_names),*                                //         DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea
+            ]                                                                  //     ]
+        }                                                                      // }
+    )
+}
+
+fn gen_fn_predicates(meta: &Meta) -> TokenStream {
+    let Some(ref predicates) = meta.kinded_attrs.predicates else {
+        return quote!();
+    };
+    let kind_name = meta.kind_name();
+
+    let fns = meta.kind_variants().map(|variant| {
+        let fn_name = predicates.method_name(variant);
+        let variant_name = &variant.ident;
+        let variant_destruct = variant.kind_ignore_pattern();
+
+        quote!(
+            pub const fn #fn_name(&self) -> bool {                             // pub const fn is_coffee(&self) -> bool {
+                ::core::matches!(self, #kind_name::#variant_name #variant_destruct) // matches!(self, DrinkKind::Coffee)
+            }                                                                  // }
+        )
+    });
+
+    quote!(#(#fns)*)
+}
+
+fn gen_fn_default_value(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.default_values {
+        return quote!();
+    }
+    let name = &meta.ident;
+    let kind_name = meta.kind_name();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let generics = &meta.generics;
+
+    // For generic enums the field types may depend on the generic parameters,
+    // so they are required to implement `Default` in the where clause.
+    let mut where_clause = meta
+        .generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| parse_quote!(where));
+    if !meta.generics.params.is_empty() {
+        let field_types = meta
+            .variants
+            .iter()
+            .filter(|variant| variant.default_with.is_none())
+            .flat_map(|variant| variant.fields.iter().map(|field| &field.ty));
+        for ty in field_types {
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::core::default::Default));
+        }
+    }
+
+    let match_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        if let Some(ref default_with) = variant.default_with {
+            let kind_destruct = variant.kind_ignore_pattern();
+            return quote!(#kind_name::#variant_name #kind_destruct => #default_with());
+        }
+        // The kept fields are taken from the kind, the rest is filled with default values
+        let kind_destruct = variant.kind_destruct_pattern();
+        let pattern = variant.destruct_pattern();
+        let (bindings, defaults): (Vec<Ident>, Vec<TokenStream>) = variant
+            .field_bindings()
+            .into_iter()
+            .zip(&variant.fields)
+            .filter(|(_, field)| !field.keep)
+            .map(|(binding, field)| {
+                // The span of the field type is used, so the compiler points to the field that lacks `Default`
+                let ty = &field.ty;
+                let default =
+                    quote_spanned!(ty.span()=> <#ty as ::core::default::Default>::default());
+                (binding, default)
+            })
+            .unzip();
+        quote!(
+            #kind_name::#variant_name #kind_destruct => {
+                #(let #bindings = #defaults;)*
+                #name::#variant_name #pattern
+            }
+        )
+    });
+
+    quote!(
+        pub fn default_value #generics (self) -> #main_enum_with_generics #where_clause { // pub fn default_value(self) -> Drink {
+            match self {                                                       //     match self {
+                #(#match_branches),*                                           //         DrinkKind::Coffee => Drink::Coffee(Default::default()),
             }                                                                  //     }
         }                                                                      // }
     )
@@ -65,28 +226,57 @@ fn gen_definition(meta: &Meta) -> TokenStream {
 fn gen_variant_definition(variant: &Variant) -> TokenStream {
     let variant_name = &variant.ident;
     let variant_attrs = &variant.attrs;
+    let fields = variant.kind_fields_definition();
 
     quote!(
         #(#[#variant_attrs])*
-        #variant_name
+        #variant_name #fields
     )
 }
 
 fn gen_impl_from_traits(meta: &Meta) -> TokenStream {
-    let kind_name = meta.kind_name();
-    let generics = &meta.generics;
+    let kind_type = meta.kind_type();
+    let kind_fn = meta.kind_fn_path();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
     let main_enum_with_generics = meta.main_enum_with_generics();
 
     quote!(
-        impl #generics From<#main_enum_with_generics> for #kind_name {         // impl<T> From<Drink<T>> for DrinkKind {
-            fn from(value: #main_enum_with_generics) -> #kind_name {           //     fn from(value: Drink<T>) -> DrinkKind {
-                value.kind()                                                   //         value.kind()
+        impl #impl_generics From<#main_enum_with_generics> for #kind_type #where_clause { // impl<T> From<Drink<T>> for DrinkKind {
+            fn from(value: #main_enum_with_generics) -> #kind_type {           //     fn from(value: Drink<T>) -> DrinkKind {
+                #kind_fn(&value)                                               //         Drink::kind(&value)
             }                                                                  //     }
         }                                                                      // }
 
-        impl #generics From<&#main_enum_with_generics> for #kind_name {        // impl<T> From<Drink<T>> for DrinkKind {
-            fn from(value: &#main_enum_with_generics) -> #kind_name {          //     fn from(value: &Drink<T>) -> DrinkKind {
-                value.kind()                                                   //         value.kind()
+        impl #impl_generics From<&#main_enum_with_generics> for #kind_type #where_clause { // impl<T> From<Drink<T>> for DrinkKind {
+            fn from(value: &#main_enum_with_generics) -> #kind_type {          //     fn from(value: &Drink<T>) -> DrinkKind {
+                #kind_fn(value)                                                //         Drink::kind(value)
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_impl_cross_partial_eq_traits(meta: &Meta) -> TokenStream {
+    let kind_type = meta.kind_type();
+    let kind_fn = meta.kind_fn_path();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+
+    quote!(
+        impl #impl_generics PartialEq<#kind_type> for #main_enum_with_generics #where_clause { // impl<T> PartialEq<DrinkKind> for Drink<T> {
+            fn eq(&self, other: &#kind_type) -> bool {                         //     fn eq(&self, other: &DrinkKind) -> bool {
+                #kind_fn(self) == *other                                       //         Drink::kind(self) == *other
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl #impl_generics PartialEq<#main_enum_with_generics> for #kind_type #where_clause { // impl<T> PartialEq<Drink<T>> for DrinkKind {
+            fn eq(&self, other: &#main_enum_with_generics) -> bool {           //     fn eq(&self, other: &Drink<T>) -> bool {
+                *self == #kind_fn(other)                                       //         *self == Drink::kind(other)
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl #impl_generics PartialEq<&#main_enum_with_generics> for #kind_type #where_clause { // impl<T> PartialEq<&Drink<T>> for DrinkKind {
+            fn eq(&self, other: &&#main_enum_with_generics) -> bool {          //     fn eq(&self, other: &&Drink<T>) -> bool {
+                *self == #kind_fn(other)                                       //         *self == Drink::kind(other)
             }                                                                  //     }
         }                                                                      // }
     )
@@ -96,7 +286,7 @@ fn gen_impl_display_trait(meta: &Meta) -> TokenStream {
     let kind_name = meta.kind_name();
     let maybe_case = meta.kinded_attrs.display;
 
-    let match_branches = meta.variants.iter().map(|variant| {
+    let match_branches = meta.kind_variants().map(|variant| {
         // Use custom rename if specified, otherwise apply case conversion
         let display_name = if let Some(ref rename) = variant.rename {
             rename.clone()
@@ -105,8 +295,9 @@ fn gen_impl_display_trait(meta: &Meta) -> TokenStream {
             apply_maybe_case(original_variant_name_str, maybe_case)
         };
         let variant_name = &variant.ident;
+        let variant_destruct = variant.kind_ignore_pattern();
         quote!(
-            #kind_name::#variant_name => write!(f, #display_name)
+            #kind_name::#variant_name #variant_destruct => write!(f, #display_name)
         )
     });
 
@@ -134,8 +325,7 @@ fn gen_impl_from_str_trait(meta: &Meta) -> TokenStream {
 
     // First priority: match custom renames (if any variant has a rename)
     let rename_match_branches: Vec<_> = meta
-        .variants
-        .iter()
+        .kind_variants()
         .filter_map(|variant| {
             variant.rename.as_ref().map(|rename| {
                 let ident = &variant.ident;
@@ -144,19 +334,45 @@ fn gen_impl_from_str_trait(meta: &Meta) -> TokenStream {
         })
         .collect();
 
-    let original_match_branches = meta.variants.iter().map(|variant| {
+    let original_match_branches = meta.kind_variants().map(|variant| {
         let ident = &variant.ident;
         let name_str = ident.to_string();
         quote!(#name_str => return Ok(#kind_name::#ident),)
     });
 
-    let alt_match_branches = meta.variants.iter().map(|variant| {
+    let alt_match_branches = meta.kind_variants().map(|variant| {
         let ident = &variant.ident;
         let name_str = ident.to_string();
         let alternatives = DisplayCase::all().map(|case| case.apply(&name_str));
         quote!(#(#alternatives)|* => return Ok(#kind_name::#ident),)
     });
 
+    // Last resort (with `ignore_case`): compare with the renames and all the alternatives ignoring
+    // the case of letters, e.g. `oPtIoN` is parsed as `Option`
+    let ignore_case_branches: Vec<_> = if meta.kinded_attrs.ignore_case {
+        meta.kind_variants()
+            .map(|variant| {
+                let ident = &variant.ident;
+                let name_str = ident.to_string();
+                let mut names: Vec<String> = variant.rename.iter().cloned().collect();
+                for alternative in
+                    DisplayCase::all().map(|case| case.apply(&name_str).to_lowercase())
+                {
+                    if !names.contains(&alternative) {
+                        names.push(alternative);
+                    }
+                }
+                quote!(
+                    if #(s.eq_ignore_ascii_case(#names))||* {
+                        return Ok(#kind_name::#ident);
+                    }
+                )
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     // Only generate the rename match block if there are any renames
     let rename_match_block = if rename_match_branches.is_empty() {
         quote!()
@@ -174,7 +390,7 @@ fn gen_impl_from_str_trait(meta: &Meta) -> TokenStream {
         impl ::core::str::FromStr for #kind_name {
             type Err = ::kinded::ParseKindError;
 
-            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+            fn from_str(s: &str) -> ::core::result::Result<Self, ::kinded::ParseKindError> {
                 #rename_match_block
 
                 // Try to match the variants as they are (original names)
@@ -190,6 +406,8 @@ fn gen_impl_from_str_trait(meta: &Meta) -> TokenStream {
                     _ => ()                                                    //      _ => ()
                 }                                                              // }
 
+                #(#ignore_case_branches)*                                      // if s.eq_ignore_ascii_case("hot_mate") || .. { return Ok(Mate::HotMate); }
+
                 // If still no success, then return an error
                 extern crate alloc;
                 use alloc::borrow::ToOwned;
@@ -209,5 +427,14 @@ fn gen_impl_kind_trait(meta: &Meta) -> TokenStream {
                 Self::all()
             }
         }
+
+        // A kind is the kind of itself, so it's accepted wherever a value with a kind is expected
+        impl ::kinded::Kinded for #kind_name {
+            type Kind = #kind_name;
+
+            fn kind(&self) -> #kind_name {
+                *self
+            }
+        }
     )
 }
@@ -0,0 +1,176 @@
+use crate::models::{Meta, Trait, Variant};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+pub fn gen_kind_path(meta: &Meta) -> TokenStream {
+    // KindedPath trait extends Kinded trait, which is not implemented for kinds with kept fields
+    let impl_kinded_path_trait = if !meta.implements_kinded() {
+        quote!()
+    } else {
+        gen_impl_kinded_path_trait(meta)
+    };
+    if !meta.has_nested() {
+        return impl_kinded_path_trait;
+    }
+    let vis = &meta.vis;
+    let name = &meta.ident;
+    let kind_name = meta.kind_name();
+    let path_name = meta.kind_path_name();
+
+    let variant_definitions = meta.kind_variants().map(|variant| {
+        let variant_name = &variant.ident;
+        match nested_kind_type(variant) {
+            Some(nested_kind) => quote!(#variant_name(#nested_kind)),
+            None => quote!(#variant_name),
+        }
+    });
+
+    let push_paths = meta.kind_variants().map(|variant| {
+        let variant_name = &variant.ident;
+        match nested_kinded_path(variant) {
+            Some(nested_kinded_path) => quote!(
+                paths.extend(
+                    <#nested_kinded_path>::all_kind_paths()
+                        .into_iter()
+                        .map(#path_name::#variant_name),
+                );
+            ),
+            None => quote!(paths.push(#path_name::#variant_name);),
+        }
+    });
+
+    let kind_branches = meta.kind_variants().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = if variant.nested {
+            quote!((_))
+        } else {
+            quote!()
+        };
+        quote!(#path_name::#variant_name #pattern => #kind_name::#variant_name)
+    });
+
+    let kind_path_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        if variant.nested {
+            let pattern = variant.destruct_pattern();
+            let binding = &variant.field_bindings()[0];
+            quote!(#name::#variant_name #pattern => #path_name::#variant_name(::kinded::KindedPath::kind_path(#binding)))
+        } else {
+            let pattern = variant.ignore_pattern();
+            quote!(#name::#variant_name #pattern => #path_name::#variant_name)
+        }
+    });
+
+    let impl_display_trait = if meta.kinded_attrs.should_skip(Trait::Display) {
+        quote!()
+    } else {
+        let display_branches = meta.kind_variants().map(|variant| {
+            let variant_name = &variant.ident;
+            if variant.nested {
+                // Point at the field when the nested kind path can't be displayed
+                let ty = &variant.fields[0].ty;
+                let nested = quote_spanned!(ty.span()=> ::kinded::__private::nested_display(nested));
+                quote!(#path_name::#variant_name(nested) => write!(f, "{}.{}", #kind_name::#variant_name, #nested))
+            } else {
+                quote!(#path_name::#variant_name => write!(f, "{}", #kind_name::#variant_name))
+            }
+        });
+        quote!(
+            impl ::core::fmt::Display for #path_name {                         // impl Display for EventKindPath {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {                                               //     match self {
+                        #(#display_branches),*                                 //         EventKindPath::Drink(nested) => write!(f, "{}.{}", EventKind::Drink, nested),
+                    }                                                          //     }
+                }
+            }                                                                  // }
+        )
+    };
+
+    quote!(
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]                           // #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis enum #path_name {                                                 // pub enum EventKindPath {
+            #(#variant_definitions),*                                          //     Ping, Drink(DrinkKind)
+        }                                                                      // }
+
+        impl #path_name {                                                      // impl EventKindPath {
+            // The paths of the nested kinds can't be listed in a const context, so unlike the kind
+            // they are collected into a Vec instead of a static slice
+            pub fn all() -> ::kinded::__private::Vec<#path_name> {             //     pub fn all() -> Vec<EventKindPath> {
+                let mut paths = ::kinded::__private::Vec::new();               //         let mut paths = Vec::new();
+                #(#push_paths)*                                                //         paths.push(EventKindPath::Ping);
+                paths                                                          //         paths
+            }                                                                  //     }
+                                                                               //
+            pub const fn kind(&self) -> #kind_name {                           //     pub const fn kind(&self) -> EventKind {
+                match self {                                                   //         match self {
+                    #(#kind_branches),*                                        //             EventKindPath::Drink(_) => EventKind::Drink,
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl #name {                                                           // impl Event {
+            pub fn kind_path(&self) -> #path_name {                            //     pub fn kind_path(&self) -> EventKindPath {
+                match self {                                                   //         match self {
+                    #(#kind_path_branches),*                                   //             Event::Drink(field_0) => EventKindPath::Drink(field_0.kind()),
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        #impl_display_trait
+        #impl_kinded_path_trait
+    )
+}
+
+fn gen_impl_kinded_path_trait(meta: &Meta) -> TokenStream {
+    let name = &meta.ident;
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    // Without nested variants the kind is the whole path
+    let (path_type, fn_kind_path, fn_all_kind_paths) = if meta.has_nested() {
+        let path_name = meta.kind_path_name();
+        (
+            quote!(#path_name),
+            quote!(#name::kind_path(self)),
+            quote!(#path_name::all()),
+        )
+    } else {
+        let kind_type = meta.kind_type();
+        (
+            quote!(#kind_type),
+            quote!(::kinded::Kinded::kind(self)),
+            quote!(::kinded::__private::Vec::from(<#kind_type as ::kinded::Kind>::all())),
+        )
+    };
+
+    quote!(
+        impl #impl_generics ::kinded::KindedPath for #main_enum_with_generics #where_clause { // impl<T> ::kinded::KindedPath for Event<T> {
+            type KindPath = #path_type;                                        //     type KindPath = EventKindPath;
+                                                                               //
+            fn kind_path(&self) -> #path_type {                                //     fn kind_path(&self) -> EventKindPath {
+                #fn_kind_path                                                  //         Event::kind_path(self)
+            }                                                                  //     }
+                                                                               //
+            fn all_kind_paths() -> ::kinded::__private::Vec<#path_type> {      //     fn all_kind_paths() -> Vec<EventKindPath> {
+                #fn_all_kind_paths                                             //         EventKindPath::all()
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+/// Get the kind path type of the nested variant, e.g. `<Drink as ::kinded::KindedPath>::KindPath`.
+/// It is the kind type, unless the nested enum has nested variants too.
+fn nested_kind_type(variant: &Variant) -> Option<TokenStream> {
+    let nested_kinded_path = nested_kinded_path(variant)?;
+    Some(quote!(<#nested_kinded_path>::KindPath))
+}
+
+/// Get the field type of the nested variant as `KindedPath`, e.g. `Drink as ::kinded::KindedPath`.
+fn nested_kinded_path(variant: &Variant) -> Option<TokenStream> {
+    if !variant.nested {
+        return None;
+    }
+    let ty = &variant.fields[0].ty;
+    Some(quote_spanned!(ty.span()=> #ty as ::kinded::KindedPath))
+}
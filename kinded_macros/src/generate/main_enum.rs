@@ -1,33 +1,69 @@
-use crate::models::{FieldsType, Meta, Variant};
+use crate::models::{FieldsType, Meta, Trait, Variant};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::{Path, Type};
 
 pub fn gen_main_enum_extra(meta: &Meta) -> TokenStream {
     let fn_kind = gen_fn_kind(meta);
+    let fn_predicates = gen_fn_predicates(meta);
+    let fn_accessors = gen_fn_accessors(meta);
     let main_enum_with_generics = meta.main_enum_with_generics();
-    let generics = &meta.generics;
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
 
-    let impl_kinded_trait = gen_impl_kinded_trait(meta);
+    // Kinded trait requires the kind to implement Kind trait, which is not possible with kept fields,
+    // and an existing kind type may not implement it
+    let impl_kinded_trait = if !meta.implements_kinded() {
+        quote!()
+    } else {
+        gen_impl_kinded_trait(meta)
+    };
+
+    // The variants of an existing kind type are unknown, so it can't be converted
+    let impl_try_from_kind_trait =
+        if meta.kinded_attrs.should_skip(Trait::TryFrom) || meta.kinded_attrs.kind_of.is_some() {
+            quote!()
+        } else {
+            gen_impl_try_from_kind_trait(meta)
+        };
+
+    // FromStr for the main enum delegates to the kind, so it's only possible when all the variants are units
+    // that map 1:1 to the kind variants, and only as long as the kind implements it
+    let impl_from_str_trait = if meta.is_unit_only()
+        && meta.extra_kinds.is_empty()
+        && !meta.has_kind_with()
+        && meta.kinded_attrs.kind_of.is_none()
+        && !meta.kinded_attrs.should_skip(Trait::FromStr)
+        && !meta.kinded_attrs.should_skip(Trait::MainFromStr)
+    {
+        gen_impl_from_str_trait(meta)
+    } else {
+        quote!()
+    };
 
     quote!(
-        impl #generics #main_enum_with_generics {                              // impl<T> Drink<T> {
+        impl #impl_generics #main_enum_with_generics #where_clause {           // impl<T> Drink<T> {
             #fn_kind                                                           //     fn kind(&self) -> DrinkKind { ... }
+            #fn_predicates                                                     //     fn is_coffee(&self) -> bool { ... }
+            #fn_accessors                                                      //     fn as_coffee(&self) -> Option<&String> { ... }
         }                                                                      // }
 
         #impl_kinded_trait                                                     // impl<T> ::kinded::Kinded for Drink<T> { .. }
+        #impl_try_from_kind_trait                                              // impl<T> TryFrom<DrinkKind> for Drink<T> { .. }
+        #impl_from_str_trait                                                   // impl FromStr for Drink { .. }
     )
 }
 
 fn gen_fn_kind(meta: &Meta) -> TokenStream {
     let name = &meta.ident;
-    let kind_name = meta.kind_name();
+    let kind_type = meta.kind_type();
     let match_branches = meta
         .variants
         .iter()
-        .map(|variant| gen_match_branch(name, &kind_name, variant));
+        .map(|variant| gen_match_branch(name, &kind_type, variant, &quote!(*)));
+    let constness = meta.kind_fn_constness();
 
     quote!(
-        pub const fn kind(&self) -> #kind_name {                               // pub const fn kind(&self) -> DrinkKind {
+        pub #constness fn kind(&self) -> #kind_type {                          // pub const fn kind(&self) -> DrinkKind {
             match self {                                                       //     match self {
                 #(#match_branches),*                                           //         Drink::Coffee(..) => DrinkKind::Coffee,
             }                                                                  //     }
@@ -35,31 +71,198 @@ fn gen_fn_kind(meta: &Meta) -> TokenStream {
     )
 }
 
-fn gen_match_branch(name: &Ident, kind_name: &Ident, variant: &Variant) -> TokenStream {
+/// Generate a match branch that maps a variant of an enum (the main enum or one of the enums
+/// generated with the same variants) to the corresponding kind variant.
+/// The kept fields are bound by reference and copied with `deref`, e.g. `**` when the fields
+/// of the matched enum are references themselves.
+pub(super) fn gen_match_branch(
+    name: &Ident,
+    kind_type: &Path,
+    variant: &Variant,
+    deref: &TokenStream,
+) -> TokenStream {
     let variant_name = &variant.ident;
-    let variant_destruct = match variant.fields_type {
-        FieldsType::Named => quote!({ .. }),
-        FieldsType::Unnamed => quote!((..)),
-        FieldsType::Unit => quote!(),
+    let variant_destruct = if variant.kind_with.is_some() || variant.flatten.is_some() {
+        variant.destruct_pattern()
+    } else {
+        variant.kept_destruct_pattern()
     };
+    let kind_value = variant.kind_value(kind_type, deref);
 
     quote!(
-        #name::#variant_name #variant_destruct => #kind_name::#variant_name
+        #name::#variant_name #variant_destruct => #kind_value
     )
 }
 
+fn gen_fn_predicates(meta: &Meta) -> TokenStream {
+    let Some(ref predicates) = meta.kinded_attrs.predicates else {
+        return quote!();
+    };
+    let name = &meta.ident;
+
+    let fns = meta.variants.iter().map(|variant| {
+        let fn_name = predicates.method_name(variant);
+        let variant_name = &variant.ident;
+        let variant_destruct = variant.ignore_pattern();
+
+        quote!(
+            pub const fn #fn_name(&self) -> bool {                             // pub const fn is_coffee(&self) -> bool {
+                ::core::matches!(self, #name::#variant_name #variant_destruct) //     matches!(self, Drink::Coffee(..))
+            }                                                                  // }
+        )
+    });
+
+    quote!(#(#fns)*)
+}
+
+fn gen_fn_accessors(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.accessors {
+        return quote!();
+    }
+    let name = &meta.ident;
+
+    let fns = meta
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| {
+            let snake_name = variant.snake_case_name();
+            let fn_as = format_ident!("as_{snake_name}");
+            let fn_as_mut = format_ident!("as_{snake_name}_mut");
+            let fn_into = format_ident!("into_{snake_name}");
+
+            let variant_name = &variant.ident;
+            let pattern = variant.destruct_pattern();
+            let bindings = variant.field_bindings();
+            let types: Vec<&Type> = variant.fields.iter().map(|field| &field.ty).collect();
+
+            // A single field is returned as it is, multiple fields are returned as a tuple
+            let (ref_type, mut_type, owned_type, value) = if let [ty] = types.as_slice() {
+                (
+                    quote!(&#ty),
+                    quote!(&mut #ty),
+                    quote!(#ty),
+                    quote!(#(#bindings)*),
+                )
+            } else {
+                (
+                    quote!((#(&#types),*)),
+                    quote!((#(&mut #types),*)),
+                    quote!((#(#types),*)),
+                    quote!((#(#bindings),*)),
+                )
+            };
+
+            quote!(
+                #[allow(unreachable_patterns)]
+                pub const fn #fn_as(&self) -> ::core::option::Option<#ref_type> { // pub const fn as_coffee(&self) -> Option<&String> {
+                    match self {                                               //     match self {
+                        #name::#variant_name #pattern => Some(#value),         //         Drink::Coffee(field_0) => Some(field_0),
+                        _ => None,                                             //         _ => None,
+                    }                                                          //     }
+                }                                                              // }
+
+                #[allow(unreachable_patterns)]
+                pub const fn #fn_as_mut(&mut self) -> ::core::option::Option<#mut_type> { // pub const fn as_coffee_mut(&mut self) -> Option<&mut String> {
+                    match self {                                               //     match self {
+                        #name::#variant_name #pattern => Some(#value),         //         Drink::Coffee(field_0) => Some(field_0),
+                        _ => None,                                             //         _ => None,
+                    }                                                          //     }
+                }                                                              // }
+
+                #[allow(unreachable_patterns)]
+                pub fn #fn_into(self) -> ::core::result::Result<#owned_type, Self> { // pub fn into_coffee(self) -> Result<String, Self> {
+                    match self {                                               //     match self {
+                        #name::#variant_name #pattern => Ok(#value),           //         Drink::Coffee(field_0) => Ok(field_0),
+                        other => Err(other),                                   //         other => Err(other),
+                    }                                                          //     }
+                }                                                              // }
+            )
+        });
+
+    quote!(#(#fns)*)
+}
+
 fn gen_impl_kinded_trait(meta: &Meta) -> TokenStream {
-    let kind_name = meta.kind_name();
+    let kind_type = meta.kind_type();
     let main_enum_with_generics = meta.main_enum_with_generics();
-    let generics = &meta.generics;
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
 
     quote!(
-        impl #generics ::kinded::Kinded for #main_enum_with_generics {         // impl<T> ::kinded::Kinded for Drink<T> {
-            type Kind = #kind_name;                                            //     type Kind = DrinkKind;
+        impl #impl_generics ::kinded::Kinded for #main_enum_with_generics #where_clause { // impl<T> ::kinded::Kinded for Drink<T> {
+            type Kind = #kind_type;                                            //     type Kind = DrinkKind;
                                                                                //
-            fn kind(&self) -> #kind_name {                                     //     fn kind(&self) -> DrinkKind {
+            fn kind(&self) -> #kind_type {                                     //     fn kind(&self) -> DrinkKind {
                 self.kind()                                                    //         self.kind()
             }                                                                  //     }
         }                                                                      // }
     )
 }
+
+fn gen_impl_try_from_kind_trait(meta: &Meta) -> TokenStream {
+    let name = &meta.ident;
+    let kind_type = meta.kind_type();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    let variant_branches = meta
+        .variants
+        .iter()
+        .filter(|variant| variant.kind_with.is_none() && variant.flatten.is_none())
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            let variant_destruct = variant.kind_ignore_pattern();
+            match variant.fields_type {
+                FieldsType::Unit => quote!(#kind_type::#variant_name => Ok(#name::#variant_name)),
+                FieldsType::Named | FieldsType::Unnamed => quote!(
+                    #kind_type::#variant_name #variant_destruct => Err(::kinded::NonUnitKindError::new(kind))
+                ),
+            }
+        });
+
+    // Extra kinds and kinds of flattened variants are produced by variants with data,
+    // so they can't be converted either
+    let synthetic_kind_branches = meta.synthetic_kind_variants().map(|kind_variant| {
+        let variant_name = &kind_variant.ident;
+        quote!(#kind_type::#variant_name => Err(::kinded::NonUnitKindError::new(kind)))
+    });
+    let match_branches = variant_branches.chain(synthetic_kind_branches);
+
+    quote!(
+        impl #impl_generics ::core::convert::TryFrom<#kind_type> for #main_enum_with_generics #where_clause {
+            type Error = ::kinded::NonUnitKindError<#kind_type>;
+
+            fn try_from(kind: #kind_type) -> ::core::result::Result<Self, ::kinded::NonUnitKindError<#kind_type>> {
+                match kind {                                                   // match kind {
+                    #(#match_branches),*                                       //     DrinkKind::Mate => Ok(Drink::Mate),
+                }                                                              //     DrinkKind::Coffee => Err(NonUnitKindError::new(kind)),
+            }                                                                  // }
+        }
+    )
+}
+
+fn gen_impl_from_str_trait(meta: &Meta) -> TokenStream {
+    let name = &meta.ident;
+    let kind_type = meta.kind_type();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    let match_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        quote!(#kind_type::#variant_name => #name::#variant_name)
+    });
+
+    quote!(
+        impl #impl_generics ::core::str::FromStr for #main_enum_with_generics #where_clause {
+            type Err = ::kinded::ParseKindError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, ::kinded::ParseKindError> {
+                let kind: #kind_type = s.parse()?;                             // let kind: DrinkKind = s.parse()?;
+                let value = match kind {                                       // let value = match kind {
+                    #(#match_branches),*                                       //     DrinkKind::Mate => Drink::Mate,
+                };                                                             // };
+                Ok(value)                                                      // Ok(value)
+            }
+        }
+    )
+}
@@ -0,0 +1,166 @@
+use super::ref_enum::pick_lifetime;
+use crate::models::{Meta, Variant};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Ident, Visibility};
+
+pub fn gen_markers(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.markers {
+        return quote!();
+    }
+    let vis = &meta.vis;
+    let name = &meta.ident;
+    let kind_name = meta.kind_name();
+    let kind_type = meta.kind_type();
+    let mod_name = meta.markers_mod_name();
+    let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+    let type_param = pick_type_param(meta);
+    let marker_vis = nested_visibility(vis);
+
+    let marker_definitions = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let doc = format!(
+            "Marker type for `{kind_name}::{}`.",
+            variant.kind_variant_ident()
+        );
+        quote!(
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            #marker_vis struct #variant_name;
+        )
+    });
+
+    let marker_impls = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let kind_variant = variant.kind_variant_ident();
+        quote!(
+            impl ::kinded::KindMarker for #mod_name::#variant_name {           // impl ::kinded::KindMarker for drink_kind::Coffee {
+                type Kind = #kind_type;                                        //     type Kind = DrinkKind;
+                const KIND: #kind_type = #kind_type::#kind_variant;            //     const KIND: DrinkKind = DrinkKind::Coffee;
+            }                                                                  // }
+        )
+    });
+
+    let payload_impls = meta
+        .variants
+        .iter()
+        .map(|variant| gen_impl_variant_payload(meta, variant));
+
+    quote!(
+        #vis mod #mod_name {                                                   // pub mod drink_kind {
+            #(#marker_definitions)*                                            //     pub struct Coffee;
+        }                                                                      // }
+
+        #(#marker_impls)*
+
+        #(#payload_impls)*
+
+        impl #impl_generics #name #ty_generics #where_clause {                 // impl Drink {
+            pub fn try_into_typed<#type_param>(                                //     pub fn try_into_typed<M>(
+                self,                                                          //         self,
+            ) -> ::core::result::Result<::kinded::Typed<Self, #type_param>, Self>
+            where                                                              //     where
+                #type_param: ::kinded::KindMarker<Kind = #kind_type>,          //         M: KindMarker<Kind = DrinkKind>,
+            {                                                                  //     {
+                ::kinded::Typed::new(self)                                     //         Typed::new(self)
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_impl_variant_payload(meta: &Meta, variant: &Variant) -> TokenStream {
+    let name = &meta.ident;
+    let mod_name = meta.markers_mod_name();
+    let variant_name = &variant.ident;
+    let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+    let lifetime = pick_lifetime(meta);
+
+    let pattern = variant.destruct_pattern();
+    let bindings = variant.field_bindings();
+    let payload = wrap_payload(quote!(#(#bindings),*), bindings.len());
+
+    let types: Vec<&syn::Type> = variant.fields.iter().map(|field| &field.ty).collect();
+    let payload_type = wrap_payload(quote!(#(#types),*), types.len());
+    let ref_type = gen_payload_type(&types, &quote!(&));
+    let mut_type = gen_payload_type(&types, &quote!(&mut));
+    let ref_type_with_lifetime = gen_payload_type(&types, &quote!(&#lifetime));
+    let mut_type_with_lifetime = gen_payload_type(&types, &quote!(&#lifetime mut));
+
+    quote!(
+        impl #impl_generics ::kinded::VariantPayload<#mod_name::#variant_name> for #name #ty_generics #where_clause {
+            type Payload = #payload_type;
+            type PayloadRef<#lifetime> = #ref_type_with_lifetime where Self: #lifetime;
+            type PayloadMut<#lifetime> = #mut_type_with_lifetime where Self: #lifetime;
+
+            #[allow(unreachable_patterns)]
+            fn variant_payload(&self) -> ::core::option::Option<#ref_type> {
+                match self {
+                    #name::#variant_name #pattern => ::core::option::Option::Some(#payload),
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[allow(unreachable_patterns)]
+            fn variant_payload_mut(&mut self) -> ::core::option::Option<#mut_type> {
+                match self {
+                    #name::#variant_name #pattern => ::core::option::Option::Some(#payload),
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[allow(unreachable_patterns)]
+            fn into_variant_payload(self) -> ::core::result::Result<#payload_type, Self> {
+                match self {
+                    #name::#variant_name #pattern => ::core::result::Result::Ok(#payload),
+                    other => ::core::result::Result::Err(other),
+                }
+            }
+        }
+    )
+}
+
+/// Generate the payload type with every field type prefixed with `ref_token`,
+/// e.g. `(&'a String, &'a bool)`.
+fn gen_payload_type(types: &[&syn::Type], ref_token: &TokenStream) -> TokenStream {
+    wrap_payload(quote!(#(#ref_token #types),*), types.len())
+}
+
+/// A single field is used as is, multiple fields are wrapped into a tuple and no fields give `()`.
+fn wrap_payload(items: TokenStream, len: usize) -> TokenStream {
+    if len == 1 { items } else { quote!((#items)) }
+}
+
+/// Get the visibility that items inside the markers module need to have the same visibility
+/// as the main enum has outside of it, e.g. private enum requires `pub(super)` markers.
+fn nested_visibility(vis: &Visibility) -> TokenStream {
+    match vis {
+        Visibility::Public(_) => quote!(pub),
+        Visibility::Inherited => quote!(pub(super)),
+        Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            if path.is_ident("crate") {
+                quote!(pub(crate))
+            } else if path.is_ident("self") {
+                quote!(pub(super))
+            } else if path.leading_colon.is_some() || path.segments[0].ident == "crate" {
+                quote!(pub(in #path))
+            } else if path.segments[0].ident == "self" {
+                let rest = path.segments.iter().skip(1);
+                quote!(pub(in super #(::#rest)*))
+            } else {
+                quote!(pub(in super::#path))
+            }
+        }
+    }
+}
+
+/// Pick a name for the marker type parameter of `try_into_typed()` that does not clash with
+/// the type parameters of the main enum.
+fn pick_type_param(meta: &Meta) -> Ident {
+    let is_taken = |name: &str| meta.generics.type_params().any(|param| param.ident == name);
+    let name = ["M", "KindedMarker"]
+        .into_iter()
+        .find(|name| !is_taken(name))
+        .unwrap_or("KindedMarkerType");
+    Ident::new(name, Span::call_site())
+}
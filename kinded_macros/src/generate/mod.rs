@@ -1,16 +1,81 @@
+mod categories;
+mod delegate;
+mod export;
 mod kind_enum;
+mod kind_path;
 mod main_enum;
+mod markers;
+mod parts;
+mod payload_structs;
+mod ref_enum;
+mod relations;
+mod remote;
+mod secondary_kinds;
+mod subsets;
+mod transitions;
+mod visitor;
 
-use crate::models::Meta;
+use crate::models::{DelegateStruct, Meta};
 use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn generate(meta: Meta) -> TokenStream {
+    // Only the kind can be provided for an enum defined in another crate
+    if meta.kinded_attrs.remote.is_some() {
+        return generate_remote(meta);
+    }
+
     let kind_enum = kind_enum::gen_kind_enum(&meta);
     let main_enum_extra = main_enum::gen_main_enum_extra(&meta);
+    let secondary_kinds = secondary_kinds::gen_secondary_kinds(&meta);
+    let subsets = subsets::gen_subsets(&meta);
+    let categories = categories::gen_categories(&meta);
+    let relations = relations::gen_relations(&meta);
+    let transitions = transitions::gen_transitions(&meta);
+    let ref_enums = ref_enum::gen_ref_enums(&meta);
+    let parts = parts::gen_parts(&meta);
+    let payload_structs = payload_structs::gen_payload_structs(&meta);
+    let visitor = visitor::gen_visitor(&meta);
+    let markers = markers::gen_markers(&meta);
+    let kind_path = kind_path::gen_kind_path(&meta);
+    let export = export::gen_export(&meta);
 
     quote!(
         #kind_enum
         #main_enum_extra
+        #secondary_kinds
+        #subsets
+        #categories
+        #relations
+        #transitions
+        #ref_enums
+        #parts
+        #payload_structs
+        #visitor
+        #markers
+        #kind_path
+        #export
+    )
+}
+
+fn generate_remote(meta: Meta) -> TokenStream {
+    let kind_enum = kind_enum::gen_kind_enum(&meta);
+    let remote = remote::gen_remote(&meta);
+    let categories = categories::gen_categories(&meta);
+    let relations = relations::gen_relations(&meta);
+
+    quote!(
+        #kind_enum
+        #remote
+        #categories
+        #relations
     )
 }
+
+pub fn generate_kind(meta: Meta) -> TokenStream {
+    kind_enum::gen_kind_derive(&meta)
+}
+
+pub fn generate_delegate(delegate: DelegateStruct) -> TokenStream {
+    delegate::gen_delegate(&delegate)
+}
@@ -0,0 +1,163 @@
+use super::main_enum::gen_match_branch;
+use crate::models::{FieldsType, Meta};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+pub fn gen_parts(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.parts {
+        return quote!();
+    }
+
+    let vis = &meta.vis;
+    let name = &meta.ident;
+    let kind_type = meta.kind_type();
+    let payload_name = format_ident!("{name}Payload");
+    let generics = &meta.generics;
+    let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+
+    let traits = meta.payload_derive_traits();
+
+    let variant_definitions = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let fields = variant.fields_definition(&quote!(), &quote!());
+        quote!(#variant_name #fields)
+    });
+
+    let kind_branches = meta
+        .variants
+        .iter()
+        .map(|variant| gen_match_branch(&payload_name, &kind_type, variant, &quote!(*)));
+
+    let into_payload_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant.destruct_pattern();
+        quote!(#name::#variant_name #pattern => #payload_name::#variant_name #pattern)
+    });
+
+    let payload_type = quote!(#payload_name #ty_generics);
+    let result_type = quote!(::core::result::Result<Self, ::kinded::KindMismatchError<#kind_type>>);
+
+    let from_payload_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant.destruct_pattern();
+        quote!(#payload_name::#variant_name #pattern => #name::#variant_name #pattern)
+    });
+
+    let same_kind_branches = gen_same_kind_branches(meta);
+
+    let constness = meta.kind_fn_constness();
+    let impl_kinded_trait = if !meta.implements_kinded() {
+        quote!()
+    } else {
+        quote!(
+            impl #impl_generics ::kinded::Kinded for #payload_name #ty_generics #where_clause {
+                type Kind = #kind_type;
+
+                fn kind(&self) -> #kind_type {
+                    self.kind()
+                }
+            }
+        )
+    };
+
+    quote!(
+        #[derive(#(#traits),*)]                                                // #[derive(Debug)]
+        #vis enum #payload_name #generics #where_clause {                     // pub enum DrinkPayload {
+            #(#variant_definitions),*                                          //     Mate, Coffee(String), Tea { variety: String, .. }
+        }                                                                      // }
+
+        impl #impl_generics #payload_name #ty_generics #where_clause {         // impl DrinkPayload {
+            pub #constness fn kind(&self) -> #kind_type {                      //     pub const fn kind(&self) -> DrinkKind {
+                match self {                                                   //         match self {
+                    #(#kind_branches),*                                        //             DrinkPayload::Coffee(..) => DrinkKind::Coffee,
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        #impl_kinded_trait
+
+        impl #impl_generics #name #ty_generics #where_clause {                 // impl Drink {
+            pub fn into_parts(self) -> (#kind_type, #payload_type) {           //     pub fn into_parts(self) -> (DrinkKind, DrinkPayload) {
+                let kind = self.kind();                                        //         let kind = self.kind();
+                let payload = match self {                                     //         let payload = match self {
+                    #(#into_payload_branches),*                                //             Drink::Coffee(field_0) => DrinkPayload::Coffee(field_0),
+                };                                                             //         };
+                (kind, payload)                                                //         (kind, payload)
+            }                                                                  //     }
+
+            pub fn from_parts(                                                 //     pub fn from_parts(
+                kind: #kind_type,                                              //         kind: DrinkKind,
+                payload: #payload_type,                                        //         payload: DrinkPayload,
+            ) -> #result_type {                                                //     ) -> Result<Self, KindMismatchError<DrinkKind>> {
+                let payload_kind = payload.kind();                             //         let payload_kind = payload.kind();
+                // The kinds are matched, so the kind is not required to implement PartialEq
+                #[allow(unreachable_patterns)]
+                let is_same_kind = match (&kind, &payload_kind) {              //         let is_same_kind = match (&kind, &payload_kind) {
+                    #(#same_kind_branches,)*                                   //             (DrinkKind::Coffee, DrinkKind::Coffee) => true,
+                    _ => false,                                                //             _ => false,
+                };                                                             //         };
+                if !is_same_kind {                                             //         if !is_same_kind {
+                    let error = ::kinded::KindMismatchError::new(kind, payload_kind); //         let error = KindMismatchError::new(kind, payload_kind);
+                    return Err(error);                                         //             return Err(error);
+                }                                                              //         }
+                let value = match payload {                                    //         let value = match payload {
+                    #(#from_payload_branches),*                                //             DrinkPayload::Coffee(field_0) => Drink::Coffee(field_0),
+                };                                                             //         };
+                Ok(value)                                                      //         Ok(value)
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+/// Generate match branches that check that two kinds are the same kind variant.
+/// The kept fields of the variant are compared too.
+fn gen_same_kind_branches(meta: &Meta) -> Vec<TokenStream> {
+    let kind_type = meta.kind_type();
+    let mut kind_variant_names: Vec<&Ident> = Vec::new();
+    let mut branches = Vec::new();
+    for variant in meta.kind_variants() {
+        // Several variants may be mapped onto the same variant of an existing kind type with `as`
+        let kind_variant_name = variant.kind_variant_ident();
+        if kind_variant_names.contains(&kind_variant_name) {
+            continue;
+        }
+        kind_variant_names.push(kind_variant_name);
+
+        let kept_bindings: Vec<Ident> = variant
+            .field_bindings()
+            .into_iter()
+            .zip(&variant.fields)
+            .filter(|(_, field)| field.keep)
+            .map(|(binding, _)| binding)
+            .collect();
+        if kept_bindings.is_empty() {
+            branches.push(
+                quote!((#kind_type::#kind_variant_name, #kind_type::#kind_variant_name) => true),
+            );
+            continue;
+        }
+        let left: Vec<Ident> = kept_bindings
+            .iter()
+            .map(|binding| format_ident!("left_{binding}"))
+            .collect();
+        let right: Vec<Ident> = kept_bindings
+            .iter()
+            .map(|binding| format_ident!("right_{binding}"))
+            .collect();
+        let (left_pattern, right_pattern) = match variant.fields_type {
+            FieldsType::Named => (
+                quote!({ #(#kept_bindings: #left),* }),
+                quote!({ #(#kept_bindings: #right),* }),
+            ),
+            FieldsType::Unnamed | FieldsType::Unit => {
+                (quote!(( #(#left),* )), quote!(( #(#right),* )))
+            }
+        };
+        branches.push(quote!(
+            (#kind_type::#kind_variant_name #left_pattern, #kind_type::#kind_variant_name #right_pattern) => {
+                true #(&& #left == #right)*
+            }
+        ));
+    }
+    branches
+}
@@ -0,0 +1,70 @@
+use crate::models::{FieldsType, Meta, Variant};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+pub fn gen_payload_structs(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.payload_structs {
+        return quote!();
+    }
+
+    let structs = meta
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| gen_payload_struct(meta, variant));
+
+    quote!(#(#structs)*)
+}
+
+fn gen_payload_struct(meta: &Meta, variant: &Variant) -> TokenStream {
+    let vis = &meta.vis;
+    let name = &meta.ident;
+    let kind_type = meta.kind_type();
+    let variant_name = &variant.ident;
+    let kind_variant = variant.kind_variant_ident();
+    let struct_name = format_ident!("{variant_name}Payload");
+    let traits = meta.payload_derive_traits();
+    let pattern = variant.destruct_pattern();
+
+    let definition = match variant.fields_type {
+        FieldsType::Named => {
+            let fields = variant.fields_definition(&quote!(#vis), &quote!());
+            quote!(#vis struct #struct_name #fields)
+        }
+        FieldsType::Unnamed => {
+            let fields = variant.fields_definition(&quote!(#vis), &quote!());
+            quote!(#vis struct #struct_name #fields;)
+        }
+        FieldsType::Unit => unreachable!("Unit variants have no payload struct"),
+    };
+
+    quote!(
+        #[derive(#(#traits),*)]                                                // #[derive(Debug)]
+        #definition                                                            // pub struct TeaPayload { pub variety: String, .. }
+
+        impl #struct_name {                                                    // impl TeaPayload {
+            pub const KIND: #kind_type = #kind_type::#kind_variant;            //     pub const KIND: DrinkKind = DrinkKind::Tea;
+        }                                                                      // }
+
+        impl From<#struct_name> for #name {                                    // impl From<TeaPayload> for Drink {
+            fn from(payload: #struct_name) -> #name {                          //     fn from(payload: TeaPayload) -> Drink {
+                let #struct_name #pattern = payload;                           //         let TeaPayload { variety, caffeine } = payload;
+                #name::#variant_name #pattern                                  //         Drink::Tea { variety, caffeine }
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl ::core::convert::TryFrom<#name> for #struct_name {                // impl TryFrom<Drink> for TeaPayload {
+            type Error = #name;                                                //     type Error = Drink;
+                                                                               //
+            #[allow(unreachable_patterns)]
+            fn try_from(value: #name) -> ::core::result::Result<Self, #name> { //     fn try_from(value: Drink) -> Result<Self, Drink> {
+                match value {                                                  //         match value {
+                    #name::#variant_name #pattern => {                         //             Drink::Tea { variety, caffeine } => {
+                        Ok(#struct_name #pattern)                              //                 Ok(TeaPayload { variety, caffeine })
+                    }                                                          //             }
+                    other => Err(other),                                       //             other => Err(other),
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
@@ -0,0 +1,159 @@
+use super::main_enum::gen_match_branch;
+use crate::models::Meta;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{GenericParam, Lifetime, LifetimeParam};
+
+/// Mutability of the references held by a generated view enum.
+#[derive(Clone, Copy)]
+enum Mutability {
+    Shared,
+    Mutable,
+}
+
+pub fn gen_ref_enums(meta: &Meta) -> TokenStream {
+    let ref_enum = if meta.kinded_attrs.ref_enum {
+        gen_view_enum(meta, Mutability::Shared)
+    } else {
+        quote!()
+    };
+
+    let mut_enum = if meta.kinded_attrs.mut_enum {
+        gen_view_enum(meta, Mutability::Mutable)
+    } else {
+        quote!()
+    };
+
+    quote!(
+        #ref_enum
+        #mut_enum
+    )
+}
+
+fn gen_view_enum(meta: &Meta, mutability: Mutability) -> TokenStream {
+    let vis = &meta.vis;
+    let name = &meta.ident;
+    let kind_type = meta.kind_type();
+    let (view_name, fn_name) = match mutability {
+        Mutability::Shared => (format_ident!("{name}Ref"), format_ident!("as_ref_enum")),
+        Mutability::Mutable => (format_ident!("{name}Mut"), format_ident!("as_mut_enum")),
+    };
+
+    let lifetime = pick_lifetime(meta);
+    let ref_token = match mutability {
+        Mutability::Shared => quote!(&#lifetime),
+        Mutability::Mutable => quote!(&#lifetime mut),
+    };
+    let self_ref = match mutability {
+        Mutability::Shared => quote!(&self),
+        Mutability::Mutable => quote!(&mut self),
+    };
+    let constness = match mutability {
+        Mutability::Shared => quote!(const),
+        Mutability::Mutable => quote!(),
+    };
+
+    // Generics of the view enum: the generics of the main enum plus the lifetime of the references.
+    let mut view_generics = meta.generics.clone();
+    view_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+    );
+    let (view_impl_generics, view_ty_generics, where_clause) = view_generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = meta.generics.split_for_impl();
+    let generic_args = meta.generic_args();
+    let view_type = quote!(#view_name<'_ #(, #generic_args)*>);
+
+    let variant_definitions = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let fields = variant.fields_definition(&quote!(), &ref_token);
+        quote!(#variant_name #fields)
+    });
+
+    let view_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant.destruct_pattern();
+        quote!(#name::#variant_name #pattern => #view_name::#variant_name #pattern)
+    });
+
+    let kind_branches = meta
+        .variants
+        .iter()
+        .map(|variant| gen_match_branch(&view_name, &kind_type, variant, &quote!(**)));
+
+    // Shared references are `Copy`, so the view is `Copy` regardless of the payload types.
+    // Clone and Copy are implemented manually, because derive would put bounds on the generics.
+    let impl_clone_copy = match mutability {
+        Mutability::Shared => quote!(
+            impl #view_impl_generics Clone for #view_name #view_ty_generics #where_clause {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl #view_impl_generics Copy for #view_name #view_ty_generics #where_clause {}
+        ),
+        Mutability::Mutable => quote!(),
+    };
+
+    let kind_fn_constness = meta.kind_fn_constness();
+    let impl_kinded_trait = if !meta.implements_kinded() {
+        quote!()
+    } else {
+        quote!(
+            impl #view_impl_generics ::kinded::Kinded for #view_name #view_ty_generics #where_clause {
+                type Kind = #kind_type;
+
+                fn kind(&self) -> #kind_type {
+                    self.kind()
+                }
+            }
+        )
+    };
+
+    quote!(
+        #vis enum #view_name #view_generics #where_clause {                   // pub enum DrinkRef<'a> {
+            #(#variant_definitions),*                                          //     Mate, Coffee(&'a String), Tea { variety: &'a String, .. }
+        }                                                                      // }
+
+        impl #impl_generics #name #ty_generics #where_clause {                 // impl Drink {
+            pub #constness fn #fn_name(#self_ref) -> #view_type {              //     pub const fn as_ref_enum(&self) -> DrinkRef<'_> {
+                match self {                                                   //         match self {
+                    #(#view_branches),*                                        //             Drink::Coffee(field_0) => DrinkRef::Coffee(field_0),
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl #view_impl_generics #view_name #view_ty_generics #where_clause {  // impl<'a> DrinkRef<'a> {
+            pub #kind_fn_constness fn kind(&self) -> #kind_type {              //     pub const fn kind(&self) -> DrinkKind {
+                match self {                                                   //         match self {
+                    #(#kind_branches),*                                        //             DrinkRef::Coffee(..) => DrinkKind::Coffee,
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        #impl_kinded_trait
+
+        #impl_clone_copy
+
+        impl #view_impl_generics From<#ref_token #name #ty_generics> for #view_name #view_ty_generics #where_clause {
+            fn from(value: #ref_token #name #ty_generics) -> Self {
+                value.#fn_name()
+            }
+        }
+    )
+}
+
+/// Pick a lifetime for the references that does not clash with the lifetimes of the main enum.
+pub(super) fn pick_lifetime(meta: &Meta) -> Lifetime {
+    let is_taken = |name: &str| {
+        meta.generics
+            .lifetimes()
+            .any(|lifetime_param| lifetime_param.lifetime.ident == name)
+    };
+    let name = ["a", "kinded"]
+        .into_iter()
+        .find(|name| !is_taken(name))
+        .unwrap_or("kinded_ref");
+    Lifetime::new(&format!("'{name}"), Span::call_site())
+}
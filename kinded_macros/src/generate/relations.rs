@@ -0,0 +1,71 @@
+use crate::models::Meta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn gen_relations(meta: &Meta) -> TokenStream {
+    if !meta.has_relations() {
+        return quote!();
+    }
+    let kind_name = meta.kind_name();
+
+    let implies_branches = meta.kind_variants().map(|variant| {
+        let variant_name = &variant.ident;
+        let implied = meta.implied_kinds(variant_name);
+        quote!(#kind_name::#variant_name => &[#(#kind_name::#implied),*])
+    });
+
+    let conflicts_branches = meta.kind_variants().map(|variant| {
+        let variant_name = &variant.ident;
+        let conflicting = meta.conflicting_kinds(variant_name);
+        quote!(#kind_name::#variant_name => &[#(#kind_name::#conflicting),*])
+    });
+
+    quote!(
+        impl #kind_name {                                                      // impl PermissionKind {
+            /// Get the kinds implied by this kind, directly or through other kinds.
+            pub const fn implies(self) -> &'static [#kind_name] {              //     pub const fn implies(self) -> &'static [PermissionKind] {
+                match self {                                                   //         match self {
+                    #(#implies_branches),*                                     //             PermissionKind::Admin => &[PermissionKind::Read, PermissionKind::Write],
+                }                                                              //         }
+            }                                                                  //     }
+                                                                               //
+            /// Get the kinds that conflict with this kind.
+            pub const fn conflicts(self) -> &'static [#kind_name] {            //     pub const fn conflicts(self) -> &'static [PermissionKind] {
+                match self {                                                   //         match self {
+                    #(#conflicts_branches),*                                   //             PermissionKind::ReadOnly => &[PermissionKind::Write],
+                }                                                              //         }
+            }                                                                  //     }
+                                                                               //
+            /// Get the given kinds together with all the kinds they imply, in the order of the variants.
+            pub fn closure<I>(kinds: I) -> ::kinded::__private::Vec<#kind_name>
+            where
+                I: ::core::iter::IntoIterator<Item = #kind_name>,
+            {
+                let kinds: ::kinded::__private::Vec<#kind_name> = kinds.into_iter().collect();
+                #kind_name::all()
+                    .iter()
+                    .copied()
+                    .filter(|kind| {
+                        kinds
+                            .iter()
+                            .any(|given| given == kind || given.implies().contains(kind))
+                    })
+                    .collect()
+            }
+                                                                               //
+            /// Check that the given kinds and the kinds they imply don't conflict with each other.
+            pub fn validate<I>(kinds: I) -> ::core::result::Result<(), ::kinded::KindConflictError<#kind_name>>
+            where
+                I: ::core::iter::IntoIterator<Item = #kind_name>,
+            {
+                let kinds = #kind_name::closure(kinds);
+                for kind in &kinds {
+                    if let Some(conflicting) = kind.conflicts().iter().find(|other| kinds.contains(other)) {
+                        return Err(::kinded::KindConflictError::new(*kind, *conflicting));
+                    }
+                }
+                Ok(())
+            }
+        }                                                                      // }
+    )
+}
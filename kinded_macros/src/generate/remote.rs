@@ -0,0 +1,100 @@
+use crate::models::Meta;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+/// Generate the extension trait that provides the kind of the remote enum.
+/// The fields are bound and checked against the declared types, so the compiler reports
+/// any difference between the remote enum and its definition.
+/// The variants of the remote enum missing in the definition fall back to the `other` kind, if specified.
+pub fn gen_remote(meta: &Meta) -> TokenStream {
+    let (Some(remote), Some(trait_name)) = (&meta.kinded_attrs.remote, meta.remote_trait_name())
+    else {
+        return quote!();
+    };
+    let vis = &meta.vis;
+    let kind_type = meta.kind_type();
+    let kind_method = meta.kind_method();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+
+    let match_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant.destruct_pattern();
+        let bindings = variant.field_bindings();
+        // The span of the field type is used, so the compiler points to the mismatched field
+        let type_checks = variant
+            .fields
+            .iter()
+            .zip(&bindings)
+            .map(|(field, binding)| {
+                let ty = &field.ty;
+                quote_spanned!(ty.span()=> let _: &#ty = #binding;)
+            });
+        let kind_value = variant.kind_value(&kind_type, &quote!(*));
+        if bindings.is_empty() {
+            return quote!(#remote::#variant_name => #kind_value);
+        }
+        quote!(
+            #remote::#variant_name #pattern => {                               // serde_json::Value::Bool(field_0) => {
+                #(#type_checks)*                                               //     let _: &bool = field_0;
+                #kind_value                                                    //     ValueKind::Bool
+            }                                                                  // }
+        )
+    });
+
+    // The match has a wildcard arm only for `#[non_exhaustive]` remote enums
+    let other_branch = match meta.kinded_attrs.other {
+        Some(ref other) => quote!(
+            #[allow(unreachable_patterns)]
+            _ => #kind_type::#other,
+        ),
+        None => quote!(),
+    };
+    // The span of the remote path is used, so the compiler points to it when some variants are missing
+    let scrutinee = quote_spanned!(remote.span()=> self);
+
+    // The definition is never constructed and read otherwise, so it would be reported as dead code
+    let def_name = &meta.ident;
+    let def_constructors = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant.destruct_pattern();
+        let bindings = variant.field_bindings();
+        let types = variant.fields.iter().map(|field| &field.ty);
+        quote!(
+            let _: #def_name #ty_generics = {
+                #(let #bindings: #types = ::core::unreachable!();)*
+                #def_name::#variant_name #pattern
+            };
+        )
+    });
+    let def_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let pattern = variant.destruct_pattern();
+        let bindings = variant.field_bindings();
+        quote!(#def_name::#variant_name #pattern => { #(let _ = #bindings;)* })
+    });
+
+    quote!(
+        #vis trait #trait_name {                                               // pub trait ValueKinded {
+            fn #kind_method(&self) -> #kind_type;                              //     fn kind(&self) -> ValueKind;
+        }                                                                      // }
+
+        impl #impl_generics #trait_name for #main_enum_with_generics #where_clause { // impl ValueKinded for serde_json::Value {
+            #[allow(unreachable_code, clippy::diverging_sub_expression)]
+            fn #kind_method(&self) -> #kind_type {                             //     fn kind(&self) -> ValueKind {
+                if false {                                                     //     if false {
+                    #(#def_constructors)*                                      //         let _: ValueDef = ValueDef::Null;
+                    let def: #def_name #ty_generics = ::core::unreachable!();  //         let def: ValueDef = unreachable!();
+                    match def {                                                //         match def {
+                        #(#def_branches)*                                      //             ValueDef::Bool(field_0) => { let _ = field_0; }
+                    }                                                          //         }
+                }                                                              //     }
+                match #scrutinee {                                             //         match self {
+                    #(#match_branches,)*                                       //             serde_json::Value::Null => ValueKind::Null,
+                    #other_branch                                              //             _ => ValueKind::Unknown,
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
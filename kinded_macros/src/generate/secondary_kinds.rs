@@ -0,0 +1,43 @@
+use super::kind_enum::gen_kind_enum;
+use crate::models::{Meta, SecondaryKind};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn gen_secondary_kinds(meta: &Meta) -> TokenStream {
+    let secondary_kinds = meta
+        .secondary_kinds
+        .iter()
+        .map(|secondary_kind| gen_secondary_kind(meta, secondary_kind));
+    quote!(#(#secondary_kinds)*)
+}
+
+fn gen_secondary_kind(meta: &Meta, secondary_kind: &SecondaryKind) -> TokenStream {
+    let kind_enum = gen_kind_enum(&secondary_kind.meta);
+    let name = &meta.ident;
+    let kind_name = secondary_kind.meta.kind_name();
+    let kind_method = secondary_kind.meta.kind_method();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    let match_branches =
+        meta.variants
+            .iter()
+            .zip(&secondary_kind.variant_kinds)
+            .map(|(variant, kind_variant)| {
+                let variant_name = &variant.ident;
+                let variant_destruct = variant.ignore_pattern();
+                quote!(#name::#variant_name #variant_destruct => #kind_name::#kind_variant)
+            });
+
+    quote!(
+        #kind_enum
+
+        impl #impl_generics #main_enum_with_generics #where_clause {           // impl<T> Drink<T> {
+            pub const fn #kind_method(&self) -> #kind_name {                   //     pub const fn category(&self) -> DrinkCategory {
+                match self {                                                   //         match self {
+                    #(#match_branches),*                                       //             Drink::Coffee(..) => DrinkCategory::Hot,
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
@@ -0,0 +1,68 @@
+use super::kind_enum::gen_kind_enum;
+use crate::models::{Meta, Subset, Trait};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn gen_subsets(meta: &Meta) -> TokenStream {
+    let subsets = meta.subsets.iter().map(|subset| gen_subset(meta, subset));
+    quote!(#(#subsets)*)
+}
+
+fn gen_subset(meta: &Meta, subset: &Subset) -> TokenStream {
+    let kind_enum = gen_kind_enum(&subset.meta);
+    let kind_name = meta.kind_name();
+    let subset_name = subset.meta.kind_name();
+    let method = &subset.method;
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+    let constness = meta.kind_fn_constness();
+    let variant_names: Vec<_> = subset.meta.variants.iter().map(|v| &v.ident).collect();
+
+    let impl_from_trait = if meta.kinded_attrs.should_skip(Trait::From) {
+        quote!()
+    } else {
+        quote!(
+            impl From<#subset_name> for #kind_name {                           // impl From<HotDrinkKind> for DrinkKind {
+                fn from(subset: #subset_name) -> #kind_name {                  //     fn from(subset: HotDrinkKind) -> DrinkKind {
+                    match subset {                                             //         match subset {
+                        #(#subset_name::#variant_names => #kind_name::#variant_names),* // HotDrinkKind::Tea => DrinkKind::Tea,
+                    }                                                          //         }
+                }                                                              //     }
+            }                                                                  // }
+        )
+    };
+
+    let impl_try_from_trait = if meta.kinded_attrs.should_skip(Trait::TryFrom) {
+        quote!()
+    } else {
+        quote!(
+            impl ::core::convert::TryFrom<#kind_name> for #subset_name {       // impl TryFrom<DrinkKind> for HotDrinkKind {
+                type Error = ::kinded::NotInSubsetError<#kind_name>;           //     type Error = NotInSubsetError<DrinkKind>;
+                                                                               //
+                #[allow(unreachable_patterns)]
+                fn try_from(kind: #kind_name) -> ::core::result::Result<Self, ::kinded::NotInSubsetError<#kind_name>> {
+                    match kind {                                               //     match kind {
+                        #(#kind_name::#variant_names => Ok(#subset_name::#variant_names),)* // DrinkKind::Tea => Ok(HotDrinkKind::Tea),
+                        other => Err(::kinded::NotInSubsetError::new(other)),  //         other => Err(NotInSubsetError::new(other)),
+                    }                                                          //     }
+                }
+            }                                                                  // }
+        )
+    };
+
+    quote!(
+        #kind_enum
+        #impl_from_trait
+        #impl_try_from_trait
+
+        impl #impl_generics #main_enum_with_generics #where_clause {           // impl<T> Drink<T> {
+            #[allow(unreachable_patterns)]
+            pub #constness fn #method(&self) -> ::core::option::Option<#subset_name> { // pub const fn hot_kind(&self) -> Option<HotDrinkKind> {
+                match self.kind() {                                            //     match self.kind() {
+                    #(#kind_name::#variant_names => Some(#subset_name::#variant_names),)* // DrinkKind::Tea => Some(HotDrinkKind::Tea),
+                    _ => None,                                                 //         _ => None,
+                }                                                              //     }
+            }                                                                  // }
+        }                                                                      // }
+    )
+}
@@ -0,0 +1,89 @@
+use crate::models::Meta;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+pub fn gen_transitions(meta: &Meta) -> TokenStream {
+    if !meta.has_transitions() {
+        return quote!();
+    }
+    let kind_name = meta.kind_name();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    let kind_names: Vec<&Ident> = meta.kind_variants().map(|variant| &variant.ident).collect();
+
+    // Initial states can't be reached from other states, terminal states can't be left
+    let initial_states = kind_names.iter().filter(|kind| {
+        !kind_names
+            .iter()
+            .any(|other| other != *kind && meta.successors(other).contains(kind))
+    });
+    let terminal_states = kind_names
+        .iter()
+        .filter(|kind| meta.successors(kind).is_empty());
+
+    let successors_branches = kind_names.iter().map(|kind| {
+        let successors = meta.successors(kind);
+        quote!(#kind_name::#kind => &[#(#kind_name::#successors),*])
+    });
+
+    let transition_patterns: Vec<TokenStream> = kind_names
+        .iter()
+        .flat_map(|kind| {
+            let kind_name = &kind_name;
+            meta.successors(kind)
+                .into_iter()
+                .map(move |successor| quote!((#kind_name::#kind, #kind_name::#successor)))
+        })
+        .collect();
+
+    quote!(
+        impl #kind_name {                                                      // impl OrderKind {
+            /// Get the kinds this kind can transition to.
+            pub const fn successors(self) -> &'static [#kind_name] {           //     pub const fn successors(self) -> &'static [OrderKind] {
+                match self {                                                   //         match self {
+                    #(#successors_branches),*                                  //             OrderKind::Pending => &[OrderKind::Paid, OrderKind::Cancelled],
+                }                                                              //         }
+            }                                                                  //     }
+                                                                               //
+            /// Check if this kind can transition to the next one.
+            pub const fn can_transition_to(self, next: #kind_name) -> bool {   //     pub const fn can_transition_to(self, next: OrderKind) -> bool {
+                ::core::matches!(                                              //         matches!(
+                    (self, next),                                              //             (self, next),
+                    #(#transition_patterns)|*                                  //             (OrderKind::Pending, OrderKind::Paid) | ..
+                )                                                              //         )
+            }                                                                  //     }
+                                                                               //
+            /// Get the kinds that can't be reached from other kinds.
+            pub const fn initial_states() -> &'static [#kind_name] {           //     pub const fn initial_states() -> &'static [OrderKind] {
+                &[#(#kind_name::#initial_states),*]                            //         &[OrderKind::Pending]
+            }                                                                  //     }
+                                                                               //
+            /// Get the kinds that can't transition to any kind.
+            pub const fn terminal_states() -> &'static [#kind_name] {          //     pub const fn terminal_states() -> &'static [OrderKind] {
+                &[#(#kind_name::#terminal_states),*]                           //         &[OrderKind::Delivered, OrderKind::Cancelled]
+            }                                                                  //     }
+                                                                               //
+            /// Check if this kind can't transition to any kind.
+            pub const fn is_terminal(self) -> bool {                           //     pub const fn is_terminal(self) -> bool {
+                self.successors().is_empty()                                   //         self.successors().is_empty()
+            }                                                                  //     }
+        }                                                                      // }
+
+        impl #impl_generics #main_enum_with_generics #where_clause {           // impl Order {
+            /// Check if the value can transition to the next one according to the transitions of their kinds.
+            pub fn check_transition(                                           //     pub fn check_transition(
+                &self,                                                         //         &self,
+                next: &Self,                                                   //         next: &Self,
+            ) -> ::core::result::Result<(), ::kinded::TransitionError<#kind_name>> { // ) -> Result<(), TransitionError<OrderKind>> {
+                let from = self.kind();                                        //         let from = self.kind();
+                let to = next.kind();                                          //         let to = next.kind();
+                if from.can_transition_to(to) {                                //         if from.can_transition_to(to) {
+                    Ok(())                                                     //             Ok(())
+                } else {                                                       //         } else {
+                    Err(::kinded::TransitionError::new(from, to))              //             Err(TransitionError::new(from, to))
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
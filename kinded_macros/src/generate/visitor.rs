@@ -0,0 +1,111 @@
+use crate::models::{Meta, Variant};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::Ident;
+
+pub fn gen_visitor(meta: &Meta) -> TokenStream {
+    let Some(ref visitor) = meta.kinded_attrs.visitor else {
+        return quote!();
+    };
+    let vis = &meta.vis;
+    let name = &meta.ident;
+    let kind_type = meta.kind_type();
+    let visitor_name = format_ident!("{name}Visitor");
+    let generics = &meta.generics;
+    let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+    let generic_args = meta.generic_args();
+    let type_param = pick_type_param(meta);
+
+    let visit_fns = meta.variants.iter().map(|variant| {
+        let fn_name = visit_fn_name(variant);
+        let params = gen_params(variant);
+        if visitor.fallback {
+            let kind_value = variant.kind_value(&kind_type, &quote!(*));
+            let bindings = variant.field_bindings();
+            let ignore_bindings = if bindings.is_empty() {
+                quote!()
+            } else {
+                quote!(let _ = (#(#bindings),*);)
+            };
+            quote!(
+                fn #fn_name(&mut self #(, #params)*) -> Self::Output {         // fn visit_tea(&mut self, variety: &String, caffeine: &bool) -> Self::Output {
+                    #ignore_bindings                                           //     let _ = (variety, caffeine);
+                    self.visit_any(#kind_value)                                //     self.visit_any(DrinkKind::Tea)
+                }                                                              // }
+            )
+        } else {
+            quote!(
+                fn #fn_name(&mut self #(, #params)*) -> Self::Output;          // fn visit_tea(&mut self, variety: &String, caffeine: &bool) -> Self::Output;
+            )
+        }
+    });
+
+    let visit_any_fn = if visitor.fallback {
+        quote!(
+            fn visit_any(&mut self, kind: #kind_type) -> Self::Output;
+        )
+    } else {
+        quote!()
+    };
+
+    let accept_branches = meta.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let fn_name = visit_fn_name(variant);
+        let pattern = variant.destruct_pattern();
+        let bindings = variant.field_bindings();
+        quote!(
+            #name::#variant_name #pattern => visitor.#fn_name(#(#bindings),*)
+        )
+    });
+
+    // The visit methods take references to the field types as they are, e.g. `&String`.
+    quote!(
+        #[allow(clippy::ptr_arg)]
+        #vis trait #visitor_name #generics #where_clause {                     // pub trait DrinkVisitor {
+            type Output;                                                       //     type Output;
+                                                                               //
+            #visit_any_fn                                                      //
+            #(#visit_fns)*                                                     //     fn visit_mate(&mut self) -> Self::Output;
+        }                                                                      // }
+
+        impl #impl_generics #name #ty_generics #where_clause {                 // impl Drink {
+            pub fn accept<#type_param: #visitor_name<#(#generic_args),*>>(     //     pub fn accept<V: DrinkVisitor>(
+                &self,                                                         //         &self,
+                visitor: &mut #type_param,                                     //         visitor: &mut V,
+            ) -> #type_param::Output {                                         //     ) -> V::Output {
+                match self {                                                   //         match self {
+                    #(#accept_branches),*                                      //             Drink::Coffee(field_0) => visitor.visit_coffee(field_0),
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+/// Get the name of the visit method for the given variant, e.g. `visit_hot_mate`.
+fn visit_fn_name(variant: &Variant) -> Ident {
+    format_ident!("visit_{}", variant.snake_case_name())
+}
+
+/// Generate parameters of the visit method: a reference to every field of the variant.
+fn gen_params(variant: &Variant) -> Vec<TokenStream> {
+    variant
+        .field_bindings()
+        .into_iter()
+        .zip(&variant.fields)
+        .map(|(binding, field)| {
+            let ty = &field.ty;
+            quote!(#binding: &#ty)
+        })
+        .collect()
+}
+
+/// Pick a name for the visitor type parameter of `accept()` that does not clash with
+/// the type parameters of the main enum.
+fn pick_type_param(meta: &Meta) -> Ident {
+    let is_taken = |name: &str| meta.generics.type_params().any(|param| param.ident == name);
+    let name = ["V", "KindedVisitor"]
+        .into_iter()
+        .find(|name| !is_taken(name))
+        .unwrap_or("KindedVisitorType");
+    Ident::new(name, Span::call_site())
+}
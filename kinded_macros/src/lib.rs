@@ -7,12 +7,13 @@
 //! This is a supporting macro crate, that should not be used directly.
 //! For the documentation please refer to [kinded](https://docs.rs/kinded/) crate.
 
+pub(crate) mod flatten;
 pub(crate) mod generate;
 pub(crate) mod models;
 pub(crate) mod parse;
 
 use proc_macro2::TokenStream;
-use syn::DeriveInput;
+use syn::{Data, DeriveInput};
 
 #[proc_macro_derive(Kinded, attributes(kinded))]
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -24,6 +25,35 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 fn expand_derive(input: proc_macro::TokenStream) -> Result<TokenStream, syn::Error> {
     let derive_input: DeriveInput =
         syn::parse(input).expect("kinded failed parse token stream as DeriveInput");
-    let meta = parse::parse_derive_input(derive_input)?;
-    Ok(generate::generate(meta))
+    // Structs delegate to one of their fields, so no kind type is generated
+    if let Data::Struct(..) = derive_input.data {
+        let delegate = parse::parse_struct_derive_input(derive_input)?;
+        return Ok(generate::generate_delegate(delegate));
+    }
+    flatten::expand(derive_input, Vec::new())
+}
+
+#[proc_macro_derive(Kind, attributes(kinded))]
+pub fn derive_kind(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_derive_kind(input)
+        .unwrap_or_else(|e| syn::Error::to_compile_error(&e))
+        .into()
+}
+
+fn expand_derive_kind(input: proc_macro::TokenStream) -> Result<TokenStream, syn::Error> {
+    let derive_input: DeriveInput =
+        syn::parse(input).expect("kinded failed parse token stream as DeriveInput");
+    let meta = parse::parse_kind_derive_input(derive_input)?;
+    Ok(generate::generate_kind(meta))
+}
+
+/// Continues the derive of an enum with flattened variants once the exported macro of an inner enum
+/// provides its kinds. It's invoked by the generated code only.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __kinded_flatten(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse::<flatten::FlattenInput>(input)
+        .and_then(flatten::FlattenInput::expand)
+        .unwrap_or_else(|e| syn::Error::to_compile_error(&e))
+        .into()
 }
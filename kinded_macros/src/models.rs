@@ -1,7 +1,9 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use std::collections::HashSet;
-use syn::{Generics, Meta as SynMeta, Path, Visibility};
+use syn::{
+    GenericParam, Generics, LitStr, Member, Meta as SynMeta, Path, Type, Visibility, ext::IdentExt,
+};
 
 /// Traits that are automatically implemented for the generated kind enum.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -16,6 +18,16 @@ pub enum Trait {
     Display,
     FromStr,
     From,
+    /// `PartialEq<DrinkKind> for Drink` and `PartialEq<Drink> for DrinkKind`
+    CrossPartialEq,
+    /// `TryFrom<DrinkKind> for Drink`
+    TryFrom,
+    /// `FromStr for Drink`, generated when all the variants are units
+    MainFromStr,
+    /// `Kind` trait and `all()` of the kind type
+    Kind,
+    /// `Kinded` and `KindedPath` traits of the main enum and its view enums
+    Kinded,
 }
 
 impl Trait {
@@ -41,6 +53,11 @@ impl Trait {
             Trait::Display,
             Trait::FromStr,
             Trait::From,
+            Trait::CrossPartialEq,
+            Trait::TryFrom,
+            Trait::MainFromStr,
+            Trait::Kind,
+            Trait::Kinded,
         ]
     }
 
@@ -54,6 +71,11 @@ impl Trait {
             Trait::Display => "Display",
             Trait::FromStr => "FromStr",
             Trait::From => "From",
+            Trait::CrossPartialEq => "CrossPartialEq",
+            Trait::TryFrom => "TryFrom",
+            Trait::MainFromStr => "MainFromStr",
+            Trait::Kind => "Kind",
+            Trait::Kinded => "Kinded",
         }
     }
 
@@ -67,6 +89,11 @@ impl Trait {
             "Display" => Some(Trait::Display),
             "FromStr" => Some(Trait::FromStr),
             "From" => Some(Trait::From),
+            "CrossPartialEq" => Some(Trait::CrossPartialEq),
+            "TryFrom" => Some(Trait::TryFrom),
+            "MainFromStr" => Some(Trait::MainFromStr),
+            "Kind" => Some(Trait::Kind),
+            "Kinded" => Some(Trait::Kinded),
             _ => None,
         }
     }
@@ -84,8 +111,55 @@ pub struct Meta {
 
     pub variants: Vec<Variant>,
 
+    /// Kind variants without a corresponding variant of the main enum, specified with `extra_kinds(...)`.
+    /// They are produced by `kind_with` functions.
+    pub extra_kinds: Vec<Variant>,
+
     /// Attributes specified with #[kinded(..)] above the enum definition.
     pub kinded_attrs: KindedAttributes,
+
+    /// Additional kind types, specified with `#[kinded(kind = .., method = ..)]` attributes.
+    pub secondary_kinds: Vec<SecondaryKind>,
+
+    /// Kind types that contain only some of the kind variants, specified with `subset(...)`.
+    pub subsets: Vec<Subset>,
+}
+
+/// Struct that derives `Kinded` by delegating to its field marked with `#[kinded(delegate)]`,
+/// e.g. `struct Envelope { id: u64, #[kinded(delegate)] body: EventBody }`.
+#[derive(Debug)]
+pub struct DelegateStruct {
+    pub ident: Ident,
+    pub generics: Generics,
+
+    /// Name or index of the delegate field.
+    pub member: Member,
+
+    /// Type of the delegate field, it must implement `Kinded`.
+    pub ty: Type,
+}
+
+/// Kind type that contains only some of the kind variants, e.g. `HotDrinkKind` returned by
+/// `Drink::hot_kind()`.
+#[derive(Debug)]
+pub struct Subset {
+    /// Meta of the subset kind type: its variants are the selected kind variants,
+    /// the derives, display and parse configuration are taken from the main kind.
+    pub meta: Meta,
+
+    /// Name of the method on the main enum that returns the subset kind, e.g. `hot_kind`.
+    pub method: Ident,
+}
+
+/// Additional kind type of the main enum with its own accessor method, e.g. `DrinkCategory`
+/// returned by `Drink::category()`.
+#[derive(Debug)]
+pub struct SecondaryKind {
+    /// Meta of the kind type: its variants are the kind variants, the rest is taken from the main enum.
+    pub meta: Meta,
+
+    /// Kind variant for every variant of the main enum, in the same order.
+    pub variant_kinds: Vec<Ident>,
 }
 
 impl Meta {
@@ -93,11 +167,201 @@ impl Meta {
     pub fn kind_name(&self) -> Ident {
         if let Some(ref kind_name) = self.kinded_attrs.kind {
             kind_name.clone()
+        } else if let Some(remote_name) = self.remote_name() {
+            format_ident!("{remote_name}Kind")
+        } else if let Some(ref kind_of) = self.kinded_attrs.kind_of {
+            let last_segment = kind_of.segments.last();
+            last_segment
+                .expect("kind_of path is not empty")
+                .ident
+                .clone()
         } else {
             format_ident!("{}Kind", self.ident)
         }
     }
 
+    /// Get the path to the kind type: the existing type specified with `kind_of = ...`,
+    /// or the name of the generated kind type.
+    pub fn kind_type(&self) -> Path {
+        match self.kinded_attrs.kind_of {
+            Some(ref kind_of) => kind_of.clone(),
+            None => Path::from(self.kind_name()),
+        }
+    }
+
+    /// Get the name of the method that returns the kind, e.g. `kind` or `category`.
+    pub fn kind_method(&self) -> Ident {
+        match self.kinded_attrs.method {
+            Some(ref method) => method.clone(),
+            None => format_ident!("kind"),
+        }
+    }
+
+    /// Variants of the kind enum: the variants of the main enum without `kind_with`,
+    /// where flattened variants are replaced by the kinds of the inner enum,
+    /// followed by the extra kinds.
+    pub fn kind_variants(&self) -> impl Iterator<Item = &Variant> {
+        self.variants
+            .iter()
+            .filter(|variant| variant.kind_with.is_none())
+            .flat_map(|variant| match variant.flatten {
+                Some(ref flatten) => flatten.kind_variants.iter().collect(),
+                None => vec![variant],
+            })
+            .chain(&self.extra_kinds)
+    }
+
+    /// Kind variants that don't correspond to a variant of the main enum:
+    /// the kinds of flattened variants and the extra kinds.
+    pub fn synthetic_kind_variants(&self) -> impl Iterator<Item = &Variant> {
+        self.variants
+            .iter()
+            .filter_map(|variant| variant.flatten.as_ref())
+            .flat_map(|flatten| &flatten.kind_variants)
+            .chain(&self.extra_kinds)
+    }
+
+    /// Check if the kind of any variant is computed with `kind_with` or taken from a flattened enum.
+    /// Then `kind()` can't be `const`, because it calls non-const functions.
+    pub fn has_kind_with(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| variant.kind_with.is_some() || variant.flatten.is_some())
+    }
+
+    /// Get the first flattened variant whose inner kinds are not known yet.
+    pub fn unresolved_flatten(&self) -> Option<&Variant> {
+        self.variants.iter().find(|variant| {
+            variant
+                .flatten
+                .as_ref()
+                .is_some_and(|flatten| flatten.inner_variants.is_none())
+        })
+    }
+
+    /// Check if any variant is marked as `nested`. Then the kind path type is generated.
+    pub fn has_nested(&self) -> bool {
+        self.variants.iter().any(|variant| variant.nested)
+    }
+
+    /// Get the name of the kind path type, e.g. `EventKindPath`.
+    pub fn kind_path_name(&self) -> Ident {
+        format_ident!("{}Path", self.kind_name())
+    }
+
+    /// `const` if `kind()` methods can be const, nothing otherwise.
+    pub fn kind_fn_constness(&self) -> TokenStream {
+        if self.has_kind_with() {
+            quote!()
+        } else {
+            quote!(const)
+        }
+    }
+
+    /// Get the name of the category type, e.g. `DrinkKindCategory`.
+    pub fn category_name(&self) -> Ident {
+        format_ident!("{}Category", self.kind_name())
+    }
+
+    /// Categories of the kind variants in the order of their first appearance, without duplicates.
+    pub fn categories(&self) -> Vec<&LitStr> {
+        let mut categories: Vec<&LitStr> = Vec::new();
+        for category in self.variants.iter().flat_map(|variant| &variant.categories) {
+            if !categories.iter().any(|c| c.value() == category.value()) {
+                categories.push(category);
+            }
+        }
+        categories
+    }
+
+    /// Check if any variant declares `implies(...)` or `conflicts(...)` relations.
+    pub fn has_relations(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| !variant.implies.is_empty() || !variant.conflicts.is_empty())
+    }
+
+    /// Get the kinds implied by the kind directly or through other kinds, in the order of the kind variants.
+    /// The implications must not have cycles.
+    pub fn implied_kinds(&self, kind: &Ident) -> Vec<&Ident> {
+        let mut implied: Vec<&Ident> = Vec::new();
+        let mut pending: Vec<&Ident> = vec![kind];
+        while let Some(current) = pending.pop() {
+            let direct = self
+                .variants
+                .iter()
+                .filter(|variant| &variant.ident == current)
+                .flat_map(|variant| &variant.implies);
+            for target in direct {
+                if !implied.contains(&target) {
+                    implied.push(target);
+                    pending.push(target);
+                }
+            }
+        }
+        self.kind_variants()
+            .map(|variant| &variant.ident)
+            .filter(|ident| implied.contains(ident))
+            .collect()
+    }
+
+    /// Get the kinds that conflict with the kind, declared on either side, in the order of the kind variants.
+    pub fn conflicting_kinds(&self, kind: &Ident) -> Vec<&Ident> {
+        let declared_by_kind = self
+            .variants
+            .iter()
+            .filter(|variant| &variant.ident == kind)
+            .flat_map(|variant| &variant.conflicts);
+        let declared_by_others = self
+            .variants
+            .iter()
+            .filter(|variant| variant.conflicts.contains(kind))
+            .map(|variant| &variant.ident);
+        let conflicting: Vec<&Ident> = declared_by_kind.chain(declared_by_others).collect();
+        self.kind_variants()
+            .map(|variant| &variant.ident)
+            .filter(|ident| conflicting.contains(ident))
+            .collect()
+    }
+
+    /// Check if any variant declares `transitions(...)`. Then the kind is a state machine.
+    pub fn has_transitions(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| !variant.transitions.is_empty())
+    }
+
+    /// Get the kinds the kind can transition to, in the declared order.
+    pub fn successors(&self, kind: &Ident) -> Vec<&Ident> {
+        self.variants
+            .iter()
+            .filter(|variant| &variant.ident == kind)
+            .flat_map(|variant| &variant.transitions)
+            .collect()
+    }
+
+    /// Check if any variant keeps fields in the kind type. Such kind type can't list all its values,
+    /// so `all()`, `Kind` and `Kinded` traits are not implemented.
+    pub fn has_kept_fields(&self) -> bool {
+        self.variants.iter().any(Variant::has_kept_fields)
+    }
+
+    /// Check if `Kinded` trait is implemented for the main enum. It requires the kind type to implement
+    /// `Kind`, so it's not implemented with `skip_derive(Kind)` (required by kept fields), and it can be
+    /// skipped with `skip_derive(Kinded)` for an existing kind type defined in another crate without `Kind`.
+    pub fn implements_kinded(&self) -> bool {
+        !self.kinded_attrs.should_skip(Trait::Kind) && !self.kinded_attrs.should_skip(Trait::Kinded)
+    }
+
+    /// Name of the module with the generated marker types: the kind name in snake_case,
+    /// e.g. `drink_kind`.
+    pub fn markers_mod_name(&self) -> Ident {
+        format_ident!(
+            "{}",
+            DisplayCase::Snake.apply(&self.kind_name().to_string())
+        )
+    }
+
     /// Get the traits that need to be derived.
     pub fn derive_traits(&self) -> Vec<Path> {
         let mut traits: Vec<Path> = Trait::default_derives()
@@ -119,10 +383,79 @@ impl Meta {
     }
 
     pub fn main_enum_with_generics(&self) -> TokenStream {
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        if let Some(ref remote) = self.kinded_attrs.remote {
+            return quote!(#remote #ty_generics);
+        }
         let type_name = &self.ident;
-        let generics = &self.generics;
 
-        quote!(#type_name #generics)
+        quote!(#type_name #ty_generics)
+    }
+
+    /// Get the path of the function that returns the kind, e.g. `Drink::kind`, or `<Value as ValueKinded>::kind` for a remote enum.
+    /// Unlike a method call, it's not ambiguous when the remote enum implements `Kinded` too.
+    pub fn kind_fn_path(&self) -> TokenStream {
+        let kind_method = self.kind_method();
+        match self.remote_trait_name() {
+            Some(trait_name) => {
+                let remote = self.main_enum_with_generics();
+                quote!(<#remote as #trait_name>::#kind_method)
+            }
+            None => {
+                let name = &self.ident;
+                quote!(#name::#kind_method)
+            }
+        }
+    }
+
+    /// Get the name of the remote enum specified with `remote = path::to::Enum`, e.g. `Value`.
+    pub fn remote_name(&self) -> Option<&Ident> {
+        let remote = self.kinded_attrs.remote.as_ref()?;
+        let last_segment = remote.segments.last();
+        Some(&last_segment.expect("remote path is not empty").ident)
+    }
+
+    /// Get the name of the extension trait that provides the kind of the remote enum, e.g. `ValueKinded`.
+    pub fn remote_trait_name(&self) -> Option<Ident> {
+        self.remote_name()
+            .map(|remote_name| format_ident!("{remote_name}Kinded"))
+    }
+
+    /// Generic parameters of the main enum as they are passed as arguments (without bounds),
+    /// e.g. `'a, T, N` for `enum Drink<'a, T: Clone, const N: usize>`.
+    pub fn generic_args(&self) -> Vec<TokenStream> {
+        self.generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Lifetime(lifetime_param) => {
+                    let lifetime = &lifetime_param.lifetime;
+                    quote!(#lifetime)
+                }
+                GenericParam::Type(type_param) => {
+                    let ident = &type_param.ident;
+                    quote!(#ident)
+                }
+                GenericParam::Const(const_param) => {
+                    let ident = &const_param.ident;
+                    quote!(#ident)
+                }
+            })
+            .collect()
+    }
+
+    /// Check if all the variants of the main enum are unit variants (have no data).
+    pub fn is_unit_only(&self) -> bool {
+        !self.variants.is_empty()
+            && self
+                .variants
+                .iter()
+                .all(|variant| matches!(variant.fields_type, FieldsType::Unit))
+    }
+
+    /// Get the traits that need to be derived for the generated payload types.
+    pub fn payload_derive_traits(&self) -> Vec<Path> {
+        self.kinded_attrs.payload_derive.clone().unwrap_or_default()
     }
 
     pub fn meta_attrs(&self) -> Vec<SynMeta> {
@@ -134,11 +467,293 @@ impl Meta {
 pub struct Variant {
     pub ident: Ident,
     pub fields_type: FieldsType,
+    pub fields: Vec<Field>,
     /// Custom display/parse name specified with `#[kinded(rename = "...")]`.
     /// When set, this overrides the automatic case conversion for Display and FromStr.
     pub rename: Option<String>,
     /// Extra attributes to apply to the generated kind variant (e.g., `#[default]`, `#[serde(rename = "...")]`).
     pub attrs: Vec<SynMeta>,
+    /// Function that constructs the default value of the variant, specified with
+    /// `#[kinded(default_with = path::to_fn)]`.
+    pub default_with: Option<Path>,
+    /// Function that computes the kind from the fields, specified with `#[kinded(kind_with = path::to_fn)]`.
+    /// Such variant has no kind variant of its own.
+    pub kind_with: Option<Path>,
+    /// The single field of the variant implements `Kinded`, specified with `#[kinded(nested)]`.
+    /// Its kind is included into the kind path.
+    pub nested: bool,
+    /// The kinds of the single field are included into the kind enum, specified with `#[kinded(flatten)]`.
+    pub flatten: Option<Flatten>,
+    /// Variant of the existing kind type (see `kind_of`) the variant is mapped to,
+    /// specified with `#[kinded(as = ...)]`.
+    pub as_kind: Option<Ident>,
+    /// Categories of the kind variant, specified with `#[kinded(category = "...")]`.
+    pub categories: Vec<LitStr>,
+    /// Kinds implied by the kind variant, specified with `#[kinded(implies(...))]`.
+    pub implies: Vec<Ident>,
+    /// Kinds the kind variant conflicts with, specified with `#[kinded(conflicts(...))]`.
+    pub conflicts: Vec<Ident>,
+    /// Kinds the kind variant can transition to, specified with `#[kinded(transitions(...))]`.
+    pub transitions: Vec<Ident>,
+}
+
+/// Variant marked with `#[kinded(flatten)]`.
+/// The kinds of the inner enum are provided by the macro exported from its derive
+/// (see `export` attribute), so they are unknown when the outer enum is parsed the first time.
+#[derive(Debug, Default)]
+pub struct Flatten {
+    /// Kind variants of the inner enum, e.g. `Mate` and `Coffee`.
+    pub inner_variants: Option<Vec<Ident>>,
+
+    /// Variants of the outer kind enum, e.g. `DrinkMate` and `DrinkCoffee`.
+    pub kind_variants: Vec<Variant>,
+}
+
+impl Variant {
+    /// Unit variant without any attributes, e.g. a variant of a generated kind type.
+    pub fn unit(ident: Ident) -> Self {
+        Variant {
+            ident,
+            fields_type: FieldsType::Unit,
+            fields: Vec::new(),
+            rename: None,
+            attrs: Vec::new(),
+            default_with: None,
+            kind_with: None,
+            nested: false,
+            flatten: None,
+            as_kind: None,
+            categories: Vec::new(),
+            implies: Vec::new(),
+            conflicts: Vec::new(),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Variant name converted to snake_case, e.g. `HotMate` -> `hot_mate`.
+    /// It is used to build names of generated methods.
+    pub fn snake_case_name(&self) -> String {
+        DisplayCase::Snake.apply(&self.ident.unraw().to_string())
+    }
+
+    /// Get the name of the first specified attribute that has no effect on a kind type
+    /// derived with `#[derive(Kind)]`, where only `rename` is supported.
+    pub fn kind_derive_unsupported_attribute(&self) -> Option<&'static str> {
+        let flags = [
+            ("attrs", !self.attrs.is_empty()),
+            ("default_with", self.default_with.is_some()),
+            ("kind_with", self.kind_with.is_some()),
+            ("nested", self.nested),
+            ("flatten", self.flatten.is_some()),
+            ("as", self.as_kind.is_some()),
+            ("category", !self.categories.is_empty()),
+            ("implies", !self.implies.is_empty()),
+            ("conflicts", !self.conflicts.is_empty()),
+            ("transitions", !self.transitions.is_empty()),
+        ];
+        flags
+            .into_iter()
+            .find(|(_, is_specified)| *is_specified)
+            .map(|(name, _)| name)
+    }
+
+    /// Names of the variables the fields are bound to when the variant is destructured:
+    /// the field names for named fields and `field_0`, `field_1`, ... for unnamed ones.
+    pub fn field_bindings(&self) -> Vec<Ident> {
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match field.ident {
+                Some(ref ident) => ident.clone(),
+                None => format_ident!("field_{index}"),
+            })
+            .collect()
+    }
+
+    /// Generate the fields part of the variant definition with every field prefixed with
+    /// `field_vis` and every field type prefixed with `type_prefix`,
+    /// e.g. `{ variety: &'a String }` for empty visibility and `&'a` prefix.
+    pub fn fields_definition(
+        &self,
+        field_vis: &TokenStream,
+        type_prefix: &TokenStream,
+    ) -> TokenStream {
+        let types = self.fields.iter().map(|field| &field.ty);
+        match self.fields_type {
+            FieldsType::Named => {
+                let names = self.fields.iter().map(|field| &field.ident);
+                quote!({ #(#field_vis #names: #type_prefix #types),* })
+            }
+            FieldsType::Unnamed => quote!(( #(#field_vis #type_prefix #types),* )),
+            FieldsType::Unit => quote!(),
+        }
+    }
+
+    /// Generate a pattern that ignores all the fields of the variant: `{ .. }`, `(..)` or nothing.
+    pub fn ignore_pattern(&self) -> TokenStream {
+        match self.fields_type {
+            FieldsType::Named => quote!({ .. }),
+            FieldsType::Unnamed => quote!((..)),
+            FieldsType::Unit => quote!(),
+        }
+    }
+
+    /// Generate a pattern that binds all the fields of the variant to `field_bindings()`:
+    /// `{ variety, caffeine }`, `(field_0)` or nothing.
+    pub fn destruct_pattern(&self) -> TokenStream {
+        let bindings = self.field_bindings();
+        match self.fields_type {
+            FieldsType::Named => quote!({ #(#bindings),* }),
+            FieldsType::Unnamed => quote!(( #(#bindings),* )),
+            FieldsType::Unit => quote!(),
+        }
+    }
+
+    /// Name of the kind variant: the variant name or the target specified with `as`.
+    pub fn kind_variant_ident(&self) -> &Ident {
+        self.as_kind.as_ref().unwrap_or(&self.ident)
+    }
+
+    /// Check if any field of the variant is kept in the kind variant.
+    pub fn has_kept_fields(&self) -> bool {
+        self.fields.iter().any(|field| field.keep)
+    }
+
+    /// Generate the fields part of the kind variant definition, which consists only of the kept fields,
+    /// e.g. `{ tenant_id: u32 }`. It is empty when no fields are kept.
+    pub fn kind_fields_definition(&self) -> TokenStream {
+        if !self.has_kept_fields() {
+            return quote!();
+        }
+        let kept_fields: Vec<&Field> = self.fields.iter().filter(|field| field.keep).collect();
+        let types = kept_fields.iter().map(|field| &field.ty);
+        match self.fields_type {
+            FieldsType::Named => {
+                let names = kept_fields.iter().map(|field| &field.ident);
+                quote!({ #(#names: #types),* })
+            }
+            FieldsType::Unnamed => quote!(( #(#types),* )),
+            FieldsType::Unit => quote!(),
+        }
+    }
+
+    /// Generate a pattern that ignores all the fields of the kind variant: `{ .. }`, `(..)` or nothing.
+    pub fn kind_ignore_pattern(&self) -> TokenStream {
+        if self.has_kept_fields() {
+            self.ignore_pattern()
+        } else {
+            quote!()
+        }
+    }
+
+    /// Generate a pattern that binds the fields of the kind variant to the same names as
+    /// `field_bindings()` does, e.g. `{ tenant_id }` or `(field_1)`.
+    pub fn kind_destruct_pattern(&self) -> TokenStream {
+        if !self.has_kept_fields() {
+            return quote!();
+        }
+        let bindings = self
+            .field_bindings()
+            .into_iter()
+            .zip(&self.fields)
+            .filter(|(_, field)| field.keep)
+            .map(|(binding, _)| binding);
+        match self.fields_type {
+            FieldsType::Named => quote!({ #(#bindings),* }),
+            FieldsType::Unnamed => quote!(( #(#bindings),* )),
+            FieldsType::Unit => quote!(),
+        }
+    }
+
+    /// Generate a pattern that binds only the kept fields of the variant and ignores the rest,
+    /// e.g. `{ tenant_id, .. }` or `(_, field_1)`.
+    pub fn kept_destruct_pattern(&self) -> TokenStream {
+        if !self.has_kept_fields() {
+            return self.ignore_pattern();
+        }
+        let bindings = self.field_bindings();
+        match self.fields_type {
+            FieldsType::Named => {
+                let kept = bindings
+                    .iter()
+                    .zip(&self.fields)
+                    .filter(|(_, field)| field.keep)
+                    .map(|(binding, _)| binding);
+                quote!({ #(#kept,)* .. })
+            }
+            FieldsType::Unnamed => {
+                let items = bindings.iter().zip(&self.fields).map(|(binding, field)| {
+                    if field.keep {
+                        quote!(#binding)
+                    } else {
+                        quote!(_)
+                    }
+                });
+                quote!(( #(#items),* ))
+            }
+            FieldsType::Unit => quote!(),
+        }
+    }
+
+    /// Generate the kind value for the variant. The kept fields are copied out of the bindings
+    /// produced by `kept_destruct_pattern()` using `deref` (e.g. `*` or `**`),
+    /// e.g. `DrinkKind::Mate` or `EventKind::UserCreated { tenant_id: *tenant_id }`.
+    /// If the kind is computed with `kind_with`, the function is called with references to all the
+    /// fields, bound by `destruct_pattern()`. The kind of a flattened variant is mapped from the kind
+    /// of its field, bound by `destruct_pattern()` as well.
+    pub fn kind_value(&self, kind_type: &Path, deref: &TokenStream) -> TokenStream {
+        let variant_name = self.kind_variant_ident();
+        if let Some(ref kind_with) = self.kind_with {
+            let bindings = self.field_bindings();
+            return quote!(#kind_with(#(#bindings),*));
+        }
+        if let Some(ref flatten) = self.flatten {
+            // The inner kind is mapped by its position, so its type doesn't need to be in scope
+            let binding = &self.field_bindings()[0];
+            let indexes = 0..flatten.kind_variants.len();
+            let outer_variants = flatten.kind_variants.iter().map(|variant| &variant.ident);
+            return quote!(
+                match ::kinded::__private::KindIndex::kind_index(&::kinded::Kinded::kind(&#deref #binding)) {
+                    #(#indexes => #kind_type::#outer_variants,)*
+                    _ => ::core::unreachable!(),
+                }
+            );
+        }
+        if !self.has_kept_fields() {
+            return quote!(#kind_type::#variant_name);
+        }
+        let bindings: Vec<Ident> = self
+            .field_bindings()
+            .into_iter()
+            .zip(&self.fields)
+            .filter(|(_, field)| field.keep)
+            .map(|(binding, _)| binding)
+            .collect();
+        let values = bindings.iter().map(|binding| quote!(#deref #binding));
+        match self.fields_type {
+            FieldsType::Named => quote!(#kind_type::#variant_name { #(#bindings: #values),* }),
+            FieldsType::Unnamed => quote!(#kind_type::#variant_name( #(#values),* )),
+            FieldsType::Unit => quote!(#kind_type::#variant_name),
+        }
+    }
+}
+
+/// A field of a variant.
+#[derive(Debug)]
+pub struct Field {
+    /// Name of the field, `None` for unnamed fields.
+    pub ident: Option<Ident>,
+    pub ty: Type,
+
+    /// Keep the field in the kind variant, specified with `#[kinded(keep)]` on the field.
+    pub keep: bool,
+}
+
+/// Configuration for the generated visitor trait.
+#[derive(Debug, Default)]
+pub struct Visitor {
+    /// Provide default implementations of `visit_<variant>()` that fall back to `visit_any()`.
+    pub fallback: bool,
 }
 
 /// This mimics syn::Fields, but without payload.
@@ -171,8 +786,77 @@ pub struct KindedAttributes {
     /// Attributes to customize implementation for Display trait
     pub display: Option<DisplayCase>,
 
+    /// Parse the kind ignoring the case of letters as a last resort in `FromStr`,
+    /// specified with `ignore_case`.
+    pub ignore_case: bool,
+
     /// Extra attributes to apply to the generated kind enum (e.g., `#[serde(rename_all = "camelCase")]`).
     pub meta_attrs: Option<Vec<SynMeta>>,
+
+    /// Generate `is_<variant>()` predicates on the main enum and the kind enum,
+    /// specified with `predicates` or `predicates(prefix = "...")`.
+    pub predicates: Option<Predicates>,
+
+    /// Generate `as_<variant>()`, `as_<variant>_mut()` and `into_<variant>()` payload accessors
+    /// on the main enum, specified with `accessors`.
+    pub accessors: bool,
+
+    /// Generate `DrinkRef<'a>` enum that holds references to the payload, specified with `ref_enum`.
+    pub ref_enum: bool,
+
+    /// Generate `DrinkMut<'a>` enum that holds mutable references to the payload, specified with `mut_enum`.
+    pub mut_enum: bool,
+
+    /// Generate `DrinkPayload` enum together with `into_parts()` and `from_parts()`, specified with `parts`.
+    pub parts: bool,
+
+    /// Generate a standalone payload struct (e.g. `TeaPayload`) per variant with data,
+    /// specified with `payload_structs`.
+    pub payload_structs: bool,
+
+    /// Traits to derive for the generated payload types, specified with `payload_derive(...)`
+    pub payload_derive: Option<Vec<Path>>,
+
+    /// Export a macro that provides the kinds to enums that flatten this one, specified with `export`.
+    pub export: bool,
+
+    /// Extra variants of the kind enum, specified with `extra_kinds(...)`
+    pub extra_kinds: Option<Vec<Ident>>,
+
+    /// Generate `default_value()` on the kind enum, specified with `default_values`.
+    pub default_values: bool,
+
+    /// Generate a marker type per variant (e.g. `drink_kind::Coffee`) and `try_into_typed()`,
+    /// specified with `markers`.
+    pub markers: bool,
+
+    /// Generate `DrinkVisitor` trait and `Drink::accept()`, specified with `visitor`
+    /// or `visitor(fallback)`.
+    pub visitor: Option<Visitor>,
+
+    /// Name of the method that returns this kind, specified with `method = ...`.
+    /// It makes the attribute define an additional kind type.
+    pub method: Option<Ident>,
+
+    /// Existing kind type to map the variants onto instead of generating a new one,
+    /// specified with `kind_of = path::to::Type`.
+    pub kind_of: Option<Path>,
+
+    /// Groups of the main enum variants that become the variants of an additional kind type,
+    /// specified with `group(Hot = [Coffee, Tea], ...)`.
+    pub groups: Option<Vec<KindGroup>>,
+
+    /// Enum defined in another crate the annotated enum mirrors, specified with `remote = path::to::Enum`.
+    /// The kind is provided by an extension trait, because the remote enum can't have inherent methods.
+    pub remote: Option<Path>,
+
+    /// Kind variant for the variants of a `#[non_exhaustive]` remote enum that are not in its definition,
+    /// specified with `other = Unknown`.
+    pub other: Option<Ident>,
+
+    /// Kind types that contain only the listed kind variants,
+    /// specified with `subset(HotDrinkKind = [Coffee, Tea], ...)`.
+    pub subsets: Option<Vec<KindGroup>>,
 }
 
 impl KindedAttributes {
@@ -183,6 +867,83 @@ impl KindedAttributes {
             .map(|traits| traits.contains(&t))
             .unwrap_or(false)
     }
+
+    /// Get the name of the first specified attribute that is supported only by the primary kind,
+    /// because it configures the generated code of the main enum rather than the kind type.
+    pub fn primary_only_attribute(&self) -> Option<&'static str> {
+        let flags = [
+            ("kind_of", self.kind_of.is_some()),
+            ("remote", self.remote.is_some()),
+            ("other", self.other.is_some()),
+            ("predicates", self.predicates.is_some()),
+            ("accessors", self.accessors),
+            ("ref_enum", self.ref_enum),
+            ("mut_enum", self.mut_enum),
+            ("parts", self.parts),
+            ("payload_structs", self.payload_structs),
+            ("payload_derive", self.payload_derive.is_some()),
+            ("export", self.export),
+            ("extra_kinds", self.extra_kinds.is_some()),
+            ("default_values", self.default_values),
+            ("markers", self.markers),
+            ("visitor", self.visitor.is_some()),
+            ("subset", self.subsets.is_some()),
+        ];
+        flags
+            .into_iter()
+            .find(|(_, is_specified)| *is_specified)
+            .map(|(name, _)| name)
+    }
+
+    /// Get the name of the first specified attribute that has no effect on a kind type
+    /// derived with `#[derive(Kind)]`, where only `display`, `ignore_case` and `skip_derive` are supported.
+    pub fn kind_derive_unsupported_attribute(&self) -> Option<&'static str> {
+        let flags = [
+            ("kind", self.kind.is_some()),
+            ("derive", self.derive.is_some()),
+            ("attrs", self.meta_attrs.is_some()),
+            ("method", self.method.is_some()),
+            ("group", self.groups.is_some()),
+        ];
+        flags
+            .into_iter()
+            .find(|(_, is_specified)| *is_specified)
+            .map(|(name, _)| name)
+            .or_else(|| self.primary_only_attribute())
+    }
+}
+
+/// Named list of variants, e.g. `Hot = [Coffee, Tea]`. It is a variant of an additional kind type
+/// that covers several variants of the main enum (`group(...)`), or a subset kind type (`subset(...)`).
+#[derive(Debug)]
+pub struct KindGroup {
+    pub ident: Ident,
+    pub variants: Vec<Ident>,
+    /// Name of the method that returns the subset kind, e.g. `hot` in `HotDrinkKind(method = hot) = [Coffee, Tea]`.
+    /// Only subsets have methods.
+    pub method: Option<Ident>,
+}
+
+/// Configuration for the generated `is_<variant>()` predicates.
+#[derive(Debug)]
+pub struct Predicates {
+    /// Prefix of the predicate names. Can be changed to avoid clashes with user defined methods.
+    pub prefix: String,
+}
+
+impl Default for Predicates {
+    fn default() -> Self {
+        Self {
+            prefix: "is_".to_string(),
+        }
+    }
+}
+
+impl Predicates {
+    /// Get the predicate name for the given variant, e.g. `is_hot_mate`.
+    pub fn method_name(&self, variant: &Variant) -> Ident {
+        format_ident!("{}{}", self.prefix, variant.snake_case_name())
+    }
 }
 
 /// This uses the same names as serde + "Title Case" variant.
@@ -272,7 +1033,10 @@ mod tests {
             ident: format_ident!("TestEnum"),
             generics: Generics::default(),
             variants: vec![],
+            extra_kinds: vec![],
             kinded_attrs,
+            secondary_kinds: vec![],
+            subsets: vec![],
         }
     }
 
@@ -374,4 +1138,49 @@ mod tests {
             .collect();
         assert_eq!(traits, vec!["Debug", "PartialEq", "Eq", "Hash"]);
     }
+
+    #[test]
+    fn variant_destruct_pattern() {
+        let named = Variant {
+            fields_type: FieldsType::Named,
+            fields: vec![
+                Field {
+                    ident: Some(format_ident!("variety")),
+                    ty: parse_quote!(String),
+                    keep: false,
+                },
+                Field {
+                    ident: Some(format_ident!("caffeine")),
+                    ty: parse_quote!(bool),
+                    keep: false,
+                },
+            ],
+            ..Variant::unit(format_ident!("Tea"))
+        };
+        assert_eq!(
+            named.destruct_pattern().to_string(),
+            quote!({ variety, caffeine }).to_string()
+        );
+
+        let unnamed = Variant {
+            fields_type: FieldsType::Unnamed,
+            fields: vec![
+                Field {
+                    ident: None,
+                    ty: parse_quote!(String),
+                    keep: false,
+                },
+                Field {
+                    ident: None,
+                    ty: parse_quote!(u32),
+                    keep: false,
+                },
+            ],
+            ..Variant::unit(format_ident!("Juice"))
+        };
+        assert_eq!(
+            unnamed.destruct_pattern().to_string(),
+            quote!((field_0, field_1)).to_string()
+        );
+    }
 }
@@ -1,70 +1,1066 @@
-use crate::models::{DisplayCase, FieldsType, KindedAttributes, Meta, Trait, Variant};
-use proc_macro2::Ident;
-use quote::ToTokens;
+use crate::models::{
+    DelegateStruct, DisplayCase, Field, FieldsType, Flatten, KindGroup, KindedAttributes, Meta,
+    Predicates, SecondaryKind, Subset, Trait, Variant, Visitor,
+};
+use proc_macro2::{Ident, Span};
+use quote::{ToTokens, format_ident};
 use std::collections::HashSet;
 use syn::{
-    Attribute, Data, DeriveInput, LitStr, Meta as SynMeta, Path, Token, bracketed, parenthesized,
+    Attribute, Data, DeriveInput, Fields, Generics, LitStr, Member, Meta as SynMeta, Path,
+    PathArguments, Token, Type, Visibility, bracketed,
+    ext::IdentExt,
+    parenthesized,
     parse::{Parse, ParseStream},
     spanned::Spanned,
+    token,
 };
 
 pub fn parse_derive_input(input: DeriveInput) -> Result<Meta, syn::Error> {
-    let kinded_attrs: KindedAttributes = {
-        match find_kinded_attr(&input)? {
-            Some(kinded_attr) => syn::parse2(kinded_attr.to_token_stream())?,
-            None => KindedAttributes::default(),
-        }
-    };
+    let (kinded_attrs, secondary_attrs) = parse_enum_kinded_attrs(&input)?;
+    let is_non_exhaustive = input
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("non_exhaustive"));
 
     let data = match input.data {
         Data::Enum(enum_data) => enum_data,
         Data::Struct(..) | Data::Union(..) => {
             return Err(syn::Error::new(
                 input.ident.span(),
-                "Kinded can be derived only on enums",
+                "Kinded can be derived only on enums and structs",
             ));
         }
     };
 
-    Ok(Meta {
+    let variants: Vec<Variant> = data
+        .variants
+        .iter()
+        .map(parse_variant)
+        .collect::<Result<_, _>>()?;
+
+    // The variant for the unknown variants of a remote enum is an extra kind too
+    let extra_kinds: Vec<Variant> = kinded_attrs
+        .extra_kinds
+        .iter()
+        .flatten()
+        .chain(&kinded_attrs.other)
+        .map(|ident| Variant::unit(ident.clone()))
+        .collect();
+
+    let primary_kind_name = match kinded_attrs.kind {
+        Some(ref kind_name) => kind_name.clone(),
+        None => format_ident!("{}Kind", input.ident),
+    };
+    let mut kind_names = vec![primary_kind_name];
+    let mut methods: Vec<Ident> = Vec::new();
+    let mut secondary_kinds = Vec::new();
+    for (span, attrs) in secondary_attrs {
+        let (Some(kind_name), Some(method)) = (attrs.kind.clone(), attrs.method.clone()) else {
+            unreachable!("kind and method are validated when the attribute is parsed");
+        };
+        if kind_names.contains(&kind_name) {
+            let msg = format!("Duplicated kind type: {kind_name}");
+            return Err(syn::Error::new(kind_name.span(), msg));
+        }
+        if methods.contains(&method) {
+            let msg = format!("Duplicated kind method: {method}");
+            return Err(syn::Error::new(method.span(), msg));
+        }
+        let secondary_kind = parse_secondary_kind(
+            &input.vis,
+            &input.ident,
+            &input.generics,
+            &variants,
+            attrs,
+            span,
+        )?;
+        kind_names.push(kind_name);
+        methods.push(method);
+        secondary_kinds.push(secondary_kind);
+    }
+
+    let kind_variants: Vec<&Variant> = variants
+        .iter()
+        .filter(|variant| variant.kind_with.is_none())
+        .chain(&extra_kinds)
+        .collect();
+    let mut subsets = Vec::new();
+    for subset in kinded_attrs.subsets.iter().flatten() {
+        if kind_names.contains(&subset.ident) {
+            let msg = format!("Duplicated kind type: {}", subset.ident);
+            return Err(syn::Error::new(subset.ident.span(), msg));
+        }
+        let subset = parse_subset(
+            &input.vis,
+            &input.ident,
+            &kind_variants,
+            &kinded_attrs,
+            subset,
+        )?;
+        if methods.contains(&subset.method) {
+            let msg = format!("Duplicated kind method: {}", subset.method);
+            return Err(syn::Error::new(subset.meta.kind_name().span(), msg));
+        }
+        kind_names.push(subset.meta.kind_name());
+        methods.push(subset.method.clone());
+        subsets.push(subset);
+    }
+
+    let meta = Meta {
         vis: input.vis,
         ident: input.ident,
         generics: input.generics,
-        variants: data.variants.iter().map(parse_variant).collect(),
+        variants,
+        extra_kinds,
         kinded_attrs,
+        secondary_kinds,
+        subsets,
+    };
+    validate_default_values(&meta)?;
+    validate_visitor(&meta)?;
+    validate_extra_kinds(&meta)?;
+    validate_kind_with(&meta)?;
+    validate_flatten(&meta)?;
+    validate_nested(&meta)?;
+    validate_keep(&meta)?;
+    validate_kind_of(&meta)?;
+    validate_skip_kinded(&meta)?;
+    validate_remote(&meta, is_non_exhaustive)?;
+    validate_categories(&meta)?;
+    validate_payload_structs(&meta)?;
+    validate_relations(&meta)?;
+    validate_transitions(&meta)?;
+    Ok(meta)
+}
+
+/// Validate `default_values` and the variant-level `default_with`.
+fn validate_default_values(meta: &Meta) -> Result<(), syn::Error> {
+    let variants = &meta.variants;
+    if !meta.kinded_attrs.default_values {
+        if let Some(variant) = variants.iter().find(|v| v.default_with.is_some()) {
+            let msg = "default_with requires default_values attribute on the enum";
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        }
+        return Ok(());
+    }
+
+    if !meta.extra_kinds.is_empty()
+        || variants
+            .iter()
+            .any(|v| v.kind_with.is_some() || v.flatten.is_some())
+    {
+        let msg = "default_values is not compatible with kind_with, flatten and extra_kinds, because some kinds don't correspond to a single variant";
+        return Err(syn::Error::new(meta.ident.span(), msg));
+    }
+    Ok(())
+}
+
+/// Validate `visitor(fallback)`: `visit_any` must not clash with the visit method of a variant.
+fn validate_visitor(meta: &Meta) -> Result<(), syn::Error> {
+    if let Some(ref visitor) = meta.kinded_attrs.visitor
+        && visitor.fallback
+        && let Some(variant) = meta.variants.iter().find(|v| v.snake_case_name() == "any")
+    {
+        let msg = "visitor(fallback) can't be used with a variant named Any, because visit_any is reserved";
+        return Err(syn::Error::new(variant.ident.span(), msg));
+    }
+    Ok(())
+}
+
+/// Validate `extra_kinds(...)`: they must not duplicate each other or the kind variants.
+fn validate_extra_kinds(meta: &Meta) -> Result<(), syn::Error> {
+    for extra_kind in &meta.extra_kinds {
+        let is_duplicated = meta
+            .variants
+            .iter()
+            .filter(|variant| variant.kind_with.is_none())
+            .chain(&meta.extra_kinds)
+            .filter(|variant| variant.ident == extra_kind.ident)
+            .count()
+            > 1;
+        if is_duplicated {
+            let msg = format!("Duplicated kind variant: {}", extra_kind.ident);
+            return Err(syn::Error::new(extra_kind.ident.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// Validate the variant-level `kind_with = path`.
+fn validate_kind_with(meta: &Meta) -> Result<(), syn::Error> {
+    let Some(variant) = meta.variants.iter().find(|v| v.kind_with.is_some()) else {
+        return Ok(());
+    };
+    let incompatible = if variant.has_kept_fields() {
+        Some("kind_with can't be used on a variant with kept fields")
+    } else if meta.kinded_attrs.markers {
+        Some(
+            "kind_with is not compatible with markers, because markers require a constant kind per variant",
+        )
+    } else if meta.kinded_attrs.payload_structs {
+        Some(
+            "kind_with is not compatible with payload_structs, because payload structs require a constant kind per variant",
+        )
+    } else {
+        None
+    };
+    match incompatible {
+        Some(msg) => Err(syn::Error::new(variant.ident.span(), msg)),
+        None => Ok(()),
+    }
+}
+
+/// Validate the variant-level `flatten` and the enum-level `export`.
+fn validate_flatten(meta: &Meta) -> Result<(), syn::Error> {
+    let variants = &meta.variants;
+    if let Some(variant) = variants.iter().find(|v| v.flatten.is_some()) {
+        let incompatible = if variant.fields.len() != 1 {
+            Some(
+                "flatten variant must have exactly one field, which is an enum that derives Kinded with export attribute",
+            )
+        } else if flatten_macro_path(&variant.fields[0].ty).is_none() {
+            Some("flatten requires the field type to be a path to an enum, e.g. `inner::Drink`")
+        } else if variant.kind_with.is_some() {
+            Some("flatten can't be used together with kind_with")
+        } else if variant.has_kept_fields() {
+            Some("flatten can't be used on a variant with kept fields")
+        } else if variants.iter().any(|v| v.nested) {
+            Some("flatten is not compatible with nested")
+        } else if meta.kinded_attrs.subsets.is_some() {
+            Some("flatten is not compatible with subset")
+        } else if meta.kinded_attrs.markers {
+            Some(
+                "flatten is not compatible with markers, because markers require a constant kind per variant",
+            )
+        } else if meta.kinded_attrs.payload_structs {
+            Some(
+                "flatten is not compatible with payload_structs, because payload structs require a constant kind per variant",
+            )
+        } else {
+            None
+        };
+        if let Some(msg) = incompatible {
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        }
+    }
+
+    if meta.kinded_attrs.export && meta.has_kept_fields() {
+        let msg = "export is not compatible with keep";
+        return Err(syn::Error::new(meta.ident.span(), msg));
+    }
+    Ok(())
+}
+
+/// Validate the variant-level `nested`.
+fn validate_nested(meta: &Meta) -> Result<(), syn::Error> {
+    let variants = &meta.variants;
+    let Some(variant) = variants.iter().find(|v| v.nested) else {
+        return Ok(());
+    };
+    let incompatible = if variant.fields.len() != 1 {
+        Some("nested variant must have exactly one field, which implements Kinded")
+    } else if !meta.generics.params.is_empty() {
+        Some("nested is not supported for enums with generics")
+    } else if variants.iter().any(|v| v.kind_with.is_some()) {
+        Some("nested is not compatible with kind_with")
+    } else if meta.has_kept_fields() {
+        Some("nested is not compatible with keep")
+    } else {
+        None
+    };
+    match incompatible {
+        Some(msg) => Err(syn::Error::new(variant.ident.span(), msg)),
+        None => Ok(()),
+    }
+}
+
+/// Validate the field-level `keep`.
+fn validate_keep(meta: &Meta) -> Result<(), syn::Error> {
+    let Some(field) = meta
+        .variants
+        .iter()
+        .flat_map(|variant| &variant.fields)
+        .find(|field| field.keep)
+    else {
+        return Ok(());
+    };
+    let kinded_attrs = &meta.kinded_attrs;
+    let incompatible = if !meta.generics.params.is_empty() {
+        Some("keep is not supported for enums with generics")
+    } else if kinded_attrs.subsets.is_some() {
+        Some("keep is not compatible with subset")
+    } else if kinded_attrs.markers {
+        Some(
+            "keep is not compatible with markers, because markers require a constant kind per variant",
+        )
+    } else if kinded_attrs.payload_structs {
+        Some(
+            "keep is not compatible with payload_structs, because payload structs require a constant kind per variant",
+        )
+    } else if !kinded_attrs.should_skip(Trait::Kind) || !kinded_attrs.should_skip(Trait::FromStr) {
+        Some(
+            "keep requires #[kinded(skip_derive(Kind, FromStr))]: a kind with kept fields can't list all its values, so all(), FromStr and the Kind trait can't be generated, and neither can the Kinded trait that requires it",
+        )
+    } else {
+        None
+    };
+    match incompatible {
+        Some(msg) => Err(syn::Error::new(field.ty.span(), msg)),
+        None => Ok(()),
+    }
+}
+
+/// Validate `kind_of = path` and the variant-level `as = ..`.
+fn validate_kind_of(meta: &Meta) -> Result<(), syn::Error> {
+    let kinded_attrs = &meta.kinded_attrs;
+    let variants = &meta.variants;
+    let Some(ref kind_of) = kinded_attrs.kind_of else {
+        if let Some(variant) = variants.iter().find(|v| v.as_kind.is_some()) {
+            let msg = "as requires kind_of attribute on the enum";
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        }
+        return Ok(());
+    };
+
+    let incompatible = [
+        ("kind", kinded_attrs.kind.is_some()),
+        ("derive", kinded_attrs.derive.is_some()),
+        ("display", kinded_attrs.display.is_some()),
+        ("ignore_case", kinded_attrs.ignore_case),
+        ("attrs", kinded_attrs.meta_attrs.is_some()),
+        ("extra_kinds", !meta.extra_kinds.is_empty()),
+        ("default_values", kinded_attrs.default_values),
+        ("export", kinded_attrs.export),
+        ("keep", meta.has_kept_fields()),
+        ("nested", variants.iter().any(|v| v.nested)),
+        ("flatten", variants.iter().any(|v| v.flatten.is_some())),
+        ("subset", kinded_attrs.subsets.is_some()),
+        (
+            "category",
+            variants.iter().any(|v| !v.categories.is_empty()),
+        ),
+    ]
+    .into_iter()
+    .find(|(_, is_specified)| *is_specified);
+    if let Some((attr_name, _)) = incompatible {
+        let msg = format!(
+            "kind_of is not compatible with {attr_name}, because the kind type is not generated"
+        );
+        return Err(syn::Error::new(kind_of.span(), msg));
+    }
+    if let Some(variant) = variants
+        .iter()
+        .find(|v| v.as_kind.is_some() && v.kind_with.is_some())
+    {
+        let msg = "as can't be used together with kind_with";
+        return Err(syn::Error::new(variant.ident.span(), msg));
+    }
+    if kinded_attrs.markers {
+        // The marker of a variant is identified by its kind, so the kinds must not repeat
+        for (i, variant) in variants.iter().enumerate() {
+            let kind_variant = variant.kind_variant_ident();
+            if let Some(other) = variants[..i]
+                .iter()
+                .find(|v| v.kind_variant_ident() == kind_variant)
+            {
+                let msg = format!(
+                    "markers require a distinct kind per variant, but {} and {} are both mapped to {kind_variant}",
+                    other.ident, variant.ident
+                );
+                return Err(syn::Error::new(variant.ident.span(), msg));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate `skip_derive(Kinded)` and `skip_derive(Kind)`, which skip the `Kinded` trait of the main enum
+/// as well: markers and the exported kinds rely on it.
+fn validate_skip_kinded(meta: &Meta) -> Result<(), syn::Error> {
+    let kinded_attrs = &meta.kinded_attrs;
+    let Some(skipped) = [Trait::Kinded, Trait::Kind]
+        .into_iter()
+        .find(|t| kinded_attrs.should_skip(*t))
+    else {
+        return Ok(());
+    };
+    let incompatible = [
+        ("markers", kinded_attrs.markers),
+        ("export", kinded_attrs.export),
+    ]
+    .into_iter()
+    .find(|(_, is_specified)| *is_specified);
+    if let Some((attr_name, _)) = incompatible {
+        let msg = format!(
+            "skip_derive({}) is not compatible with {attr_name}, because it requires the Kinded trait",
+            skipped.as_str()
+        );
+        return Err(syn::Error::new(meta.ident.span(), msg));
+    }
+    Ok(())
+}
+
+/// Validate `remote = path` and `other = ..`: only the kind can be derived for an enum defined elsewhere,
+/// and the definition of a `#[non_exhaustive]` enum needs a kind for the variants it doesn't list.
+fn validate_remote(meta: &Meta, is_non_exhaustive: bool) -> Result<(), syn::Error> {
+    let kinded_attrs = &meta.kinded_attrs;
+    let variants = &meta.variants;
+    let Some(ref remote) = kinded_attrs.remote else {
+        if let Some(ref other) = kinded_attrs.other {
+            let msg = "other requires remote attribute on the enum";
+            return Err(syn::Error::new(other.span(), msg));
+        }
+        return Ok(());
+    };
+    if is_non_exhaustive && kinded_attrs.other.is_none() {
+        let msg = "The remote enum is #[non_exhaustive], so its variants can't be matched exhaustively: add a kind variant for the unknown variants with `other = Unknown`";
+        return Err(syn::Error::new(remote.span(), msg));
+    }
+    let incompatible = [
+        ("kind_of", kinded_attrs.kind_of.is_some()),
+        ("predicates", kinded_attrs.predicates.is_some()),
+        ("accessors", kinded_attrs.accessors),
+        ("ref_enum", kinded_attrs.ref_enum),
+        ("mut_enum", kinded_attrs.mut_enum),
+        ("parts", kinded_attrs.parts),
+        ("payload_structs", kinded_attrs.payload_structs),
+        ("export", kinded_attrs.export),
+        ("default_values", kinded_attrs.default_values),
+        ("markers", kinded_attrs.markers),
+        ("visitor", kinded_attrs.visitor.is_some()),
+        ("subset", kinded_attrs.subsets.is_some()),
+        ("method", !meta.secondary_kinds.is_empty()),
+        ("keep", meta.has_kept_fields()),
+        ("kind_with", variants.iter().any(|v| v.kind_with.is_some())),
+        ("nested", variants.iter().any(|v| v.nested)),
+        ("flatten", variants.iter().any(|v| v.flatten.is_some())),
+        (
+            "transitions",
+            variants.iter().any(|v| !v.transitions.is_empty()),
+        ),
+    ]
+    .into_iter()
+    .find(|(_, is_specified)| *is_specified);
+    if let Some((attr_name, _)) = incompatible {
+        let msg = format!(
+            "remote is not compatible with {attr_name}, because only the kind can be provided for the remote enum"
+        );
+        return Err(syn::Error::new(remote.span(), msg));
+    }
+    Ok(())
+}
+
+/// Validate the variant-level `category = ".."`: the categories must be valid and distinct variant names.
+fn validate_categories(meta: &Meta) -> Result<(), syn::Error> {
+    let variants = &meta.variants;
+    if let Some(variant) = variants.iter().find(|v| !v.categories.is_empty()) {
+        let incompatible = if variant.kind_with.is_some() {
+            Some(
+                "category can't be used on a variant with kind_with, because it has no kind variant of its own",
+            )
+        } else if variant.flatten.is_some() {
+            Some("category can't be used on a flattened variant")
+        } else if meta.has_kept_fields() {
+            Some(
+                "category is not compatible with keep, because the members of a category are listed as constants",
+            )
+        } else {
+            None
+        };
+        if let Some(msg) = incompatible {
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        }
+    }
+
+    let mut category_variants: Vec<(String, &LitStr)> = Vec::new();
+    for category in variants.iter().flat_map(|variant| &variant.categories) {
+        let category_variant = DisplayCase::Pascal.apply(&category.value());
+        if syn::parse_str::<Ident>(&category_variant).is_err() {
+            let msg = format!("Invalid category name: \"{}\"", category.value());
+            return Err(syn::Error::new(category.span(), msg));
+        }
+        match category_variants
+            .iter()
+            .find(|(name, _)| name == &category_variant)
+        {
+            Some((_, other)) if other.value() != category.value() => {
+                let msg = format!(
+                    "Category \"{}\" conflicts with \"{}\", both are named {category_variant}",
+                    category.value(),
+                    other.value()
+                );
+                return Err(syn::Error::new(category.span(), msg));
+            }
+            Some(_) => {}
+            None => category_variants.push((category_variant, category)),
+        }
+    }
+    Ok(())
+}
+
+/// Validate `payload_structs`.
+fn validate_payload_structs(meta: &Meta) -> Result<(), syn::Error> {
+    if meta.kinded_attrs.payload_structs && !meta.generics.params.is_empty() {
+        let msg = "payload_structs is not supported for enums with generics";
+        return Err(syn::Error::new(meta.generics.span(), msg));
+    }
+    Ok(())
+}
+
+/// Parse the input of `#[derive(Kinded)]` on a struct, which delegates to the field marked
+/// with `#[kinded(delegate)]`.
+pub fn parse_struct_derive_input(input: DeriveInput) -> Result<DelegateStruct, syn::Error> {
+    let Data::Struct(data) = input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "Kinded can be derived only on enums and structs",
+        ));
+    };
+    if let Some(attr) = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("kinded"))
+    {
+        let msg = "#[kinded(..)] attributes are not supported on structs, mark the field to delegate to with #[kinded(delegate)] instead";
+        return Err(syn::Error::new(attr.span(), msg));
+    }
+
+    let mut delegate: Option<(Member, Type)> = None;
+    for (index, field) in data.fields.iter().enumerate() {
+        if !parse_delegate_field(field)? {
+            continue;
+        }
+        if delegate.is_some() {
+            let msg = "Only one field can be marked with #[kinded(delegate)]";
+            return Err(syn::Error::new(field.span(), msg));
+        }
+        let member = match field.ident {
+            Some(ref ident) => Member::Named(ident.clone()),
+            None => Member::from(index),
+        };
+        delegate = Some((member, field.ty.clone()));
+    }
+    let Some((member, ty)) = delegate else {
+        let msg = "Kinded can be derived on a struct only when one of its fields is marked with #[kinded(delegate)]";
+        return Err(syn::Error::new(input.ident.span(), msg));
+    };
+
+    Ok(DelegateStruct {
+        ident: input.ident,
+        generics: input.generics,
+        member,
+        ty,
+    })
+}
+
+/// Check if the struct field is marked with `#[kinded(delegate)]`.
+fn parse_delegate_field(field: &syn::Field) -> Result<bool, syn::Error> {
+    let mut delegate = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("kinded") {
+            attr.parse_args_with(|input: ParseStream| {
+                while !input.is_empty() {
+                    let attr_name: Ident = input.parse()?;
+                    if attr_name == "delegate" {
+                        delegate = true;
+                    } else {
+                        let msg = format!("Unknown field attribute: {attr_name}");
+                        return Err(syn::Error::new(attr_name.span(), msg));
+                    }
+
+                    // Parse `,` if not at end
+                    if !input.is_empty() {
+                        let _: Token![,] = input.parse()?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(delegate)
+}
+
+/// Parse the input of `#[derive(Kind)]`: a fieldless enum that is a kind type itself.
+pub fn parse_kind_derive_input(input: DeriveInput) -> Result<Meta, syn::Error> {
+    match input.data {
+        Data::Enum(ref enum_data) => {
+            if let Some(variant) = enum_data
+                .variants
+                .iter()
+                .find(|variant| !matches!(variant.fields, Fields::Unit))
+            {
+                let msg = format!(
+                    "Kind can be derived only on fieldless enums, but variant {} has fields",
+                    variant.ident
+                );
+                return Err(syn::Error::new(variant.fields.span(), msg));
+            }
+        }
+        Data::Struct(..) | Data::Union(..) => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Kind can be derived only on enums",
+            ));
+        }
+    }
+    if let Some(attr) = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("kinded"))
+        .nth(1)
+    {
+        let msg = "Multiple #[kinded(..)] attributes are not supported by derive(Kind)";
+        return Err(syn::Error::new(attr.span(), msg));
+    }
+
+    let ident_span = input.ident.span();
+    let mut meta = parse_derive_input(input)?;
+    if let Some(attr_name) = meta.kinded_attrs.kind_derive_unsupported_attribute() {
+        let msg = format!("{attr_name} is not supported by derive(Kind)");
+        return Err(syn::Error::new(ident_span, msg));
+    }
+    for t in [Trait::Kind, Trait::Kinded] {
+        if meta.kinded_attrs.should_skip(t) {
+            let msg = format!(
+                "skip_derive({}) is not supported by derive(Kind)",
+                t.as_str()
+            );
+            return Err(syn::Error::new(ident_span, msg));
+        }
+    }
+    for variant in &meta.variants {
+        if let Some(attr_name) = variant.kind_derive_unsupported_attribute() {
+            let msg = format!("{attr_name} is not supported by derive(Kind)");
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        }
+    }
+
+    // The enum is the kind type itself
+    meta.kinded_attrs.kind = Some(meta.ident.clone());
+    Ok(meta)
+}
+
+/// Validate `transitions(...)`: the targets must be kind variants listed once.
+fn validate_transitions(meta: &Meta) -> Result<(), syn::Error> {
+    if !meta.has_transitions() {
+        return Ok(());
+    }
+    let kind_names: Vec<&Ident> = meta.kind_variants().map(|variant| &variant.ident).collect();
+
+    for variant in &meta.variants {
+        for (index, target) in variant.transitions.iter().enumerate() {
+            if !kind_names.contains(&target) {
+                let msg = format!("Unknown kind variant: {target}");
+                return Err(syn::Error::new(target.span(), msg));
+            }
+            if variant.transitions[..index].contains(target) {
+                let msg = format!("Duplicated transition: {target}");
+                return Err(syn::Error::new(target.span(), msg));
+            }
+        }
+        if !variant.transitions.is_empty() {
+            let incompatible = if variant.kind_with.is_some() {
+                Some(
+                    "transitions can't be used on a variant with kind_with, because it has no kind variant of its own",
+                )
+            } else if variant.flatten.is_some() {
+                Some("transitions can't be used on a flattened variant")
+            } else if meta.has_kept_fields() {
+                Some(
+                    "transitions are not compatible with keep, because the successors are listed as constants",
+                )
+            } else if meta.kinded_attrs.kind_of.is_some() {
+                Some(
+                    "transitions are not compatible with kind_of, because the kind type is not generated",
+                )
+            } else {
+                None
+            };
+            if let Some(msg) = incompatible {
+                return Err(syn::Error::new(variant.ident.span(), msg));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate `implies(...)` and `conflicts(...)` relations: the targets must be kind variants,
+/// the implications must not have cycles and a kind must not imply conflicting kinds.
+fn validate_relations(meta: &Meta) -> Result<(), syn::Error> {
+    if !meta.has_relations() {
+        return Ok(());
+    }
+    let kind_names: Vec<&Ident> = meta.kind_variants().map(|variant| &variant.ident).collect();
+
+    for variant in &meta.variants {
+        let relations = variant
+            .implies
+            .iter()
+            .map(|target| ("imply", target))
+            .chain(
+                variant
+                    .conflicts
+                    .iter()
+                    .map(|target| ("conflict with", target)),
+            );
+        for (relation, target) in relations {
+            if target == &variant.ident {
+                let msg = format!("{target} can't {relation} itself");
+                return Err(syn::Error::new(target.span(), msg));
+            }
+            if !kind_names.contains(&target) {
+                let msg = format!("Unknown kind variant: {target}");
+                return Err(syn::Error::new(target.span(), msg));
+            }
+        }
+        if !variant.implies.is_empty() || !variant.conflicts.is_empty() {
+            let incompatible = if variant.kind_with.is_some() {
+                Some(
+                    "implies and conflicts can't be used on a variant with kind_with, because it has no kind variant of its own",
+                )
+            } else if variant.flatten.is_some() {
+                Some("implies and conflicts can't be used on a flattened variant")
+            } else if meta.has_kept_fields() {
+                Some(
+                    "implies and conflicts are not compatible with keep, because the related kinds are listed as constants",
+                )
+            } else if meta.kinded_attrs.kind_of.is_some() {
+                Some(
+                    "implies and conflicts are not compatible with kind_of, because the kind type is not generated",
+                )
+            } else {
+                None
+            };
+            if let Some(msg) = incompatible {
+                return Err(syn::Error::new(variant.ident.span(), msg));
+            }
+        }
+    }
+
+    for variant in &meta.variants {
+        if let Some(cycle) = find_implication_cycle(meta, &variant.ident, &mut vec![&variant.ident])
+        {
+            let path: Vec<String> = cycle.iter().map(|ident| ident.to_string()).collect();
+            let msg = format!("Cyclic implication: {}", path.join(" -> "));
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        }
+    }
+
+    for kind in &kind_names {
+        let mut closure = meta.implied_kinds(kind);
+        closure.insert(0, kind);
+        for a in &closure {
+            if let Some(b) = meta
+                .conflicting_kinds(a)
+                .into_iter()
+                .find(|b| closure.contains(b))
+            {
+                let msg = format!("{kind} implies conflicting kinds: {a} conflicts with {b}");
+                return Err(syn::Error::new(kind.span(), msg));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Follow the implications starting from the last kind of `path` and return the path that
+/// leads back to `start`, if any.
+fn find_implication_cycle<'a>(
+    meta: &'a Meta,
+    start: &'a Ident,
+    path: &mut Vec<&'a Ident>,
+) -> Option<Vec<&'a Ident>> {
+    let current = *path.last().expect("path starts with the start kind");
+    let targets = meta
+        .variants
+        .iter()
+        .filter(|variant| &variant.ident == current)
+        .flat_map(|variant| &variant.implies);
+    for target in targets {
+        if target == start {
+            let mut cycle = path.clone();
+            cycle.push(target);
+            return Some(cycle);
+        }
+        // Cycles that don't go through the start kind are reported when starting from their own kinds
+        if path.contains(&target) {
+            continue;
+        }
+        path.push(target);
+        if let Some(cycle) = find_implication_cycle(meta, start, path) {
+            return Some(cycle);
+        }
+        path.pop();
+    }
+    None
+}
+
+/// Build a subset kind type. It has the same derives, display and parse configuration as the main kind,
+/// but the conversions from the main enum are replaced with the ones from the main kind.
+fn parse_subset(
+    vis: &Visibility,
+    ident: &Ident,
+    kind_variants: &[&Variant],
+    kinded_attrs: &KindedAttributes,
+    subset: &KindGroup,
+) -> Result<Subset, syn::Error> {
+    let mut variants: Vec<Variant> = Vec::new();
+    for variant_name in &subset.variants {
+        let Some(kind_variant) = kind_variants.iter().find(|v| &v.ident == variant_name) else {
+            let msg = format!("Unknown kind variant in subset: {variant_name}");
+            return Err(syn::Error::new(variant_name.span(), msg));
+        };
+        if variants.iter().any(|v| &v.ident == variant_name) {
+            let msg = format!("Duplicated kind variant in subset: {variant_name}");
+            return Err(syn::Error::new(variant_name.span(), msg));
+        }
+        variants.push(Variant {
+            rename: kind_variant.rename.clone(),
+            attrs: kind_variant.attrs.clone(),
+            ..Variant::unit(variant_name.clone())
+        });
+    }
+
+    let mut skip_derive = kinded_attrs.skip_derive.clone().unwrap_or_default();
+    skip_derive.extend([Trait::From, Trait::CrossPartialEq]);
+    let subset_attrs = KindedAttributes {
+        kind: Some(subset.ident.clone()),
+        derive: kinded_attrs.derive.clone(),
+        skip_derive: Some(skip_derive),
+        display: kinded_attrs.display,
+        ignore_case: kinded_attrs.ignore_case,
+        meta_attrs: kinded_attrs.meta_attrs.clone(),
+        ..KindedAttributes::default()
+    };
+
+    let method = match subset.method {
+        Some(ref method) => method.clone(),
+        None => subset_method(ident, &subset.ident)?,
+    };
+    if method == "kind" {
+        let msg = "method `kind` is reserved for the primary kind";
+        return Err(syn::Error::new(method.span(), msg));
+    }
+
+    Ok(Subset {
+        meta: Meta {
+            vis: vis.clone(),
+            ident: ident.clone(),
+            generics: Generics::default(),
+            variants,
+            extra_kinds: Vec::new(),
+            kinded_attrs: subset_attrs,
+            secondary_kinds: Vec::new(),
+            subsets: Vec::new(),
+        },
+        method,
     })
 }
 
-fn parse_variant(variant: &syn::Variant) -> Variant {
-    let rename = find_variant_kinded_rename(&variant.attrs);
-    let attrs = find_variant_kinded_attrs(&variant.attrs);
-    Variant {
+/// Derive the name of the subset method from the subset name without the words of the main enum name,
+/// e.g. `HotDrinkKind` of `Drink` gives `hot_kind`, while `Drinkable` stays `drinkable`.
+fn subset_method(ident: &Ident, subset_ident: &Ident) -> Result<Ident, syn::Error> {
+    let subset_name = DisplayCase::Snake.apply(&subset_ident.unraw().to_string());
+    let enum_name = DisplayCase::Snake.apply(&ident.unraw().to_string());
+
+    let mut words: Vec<&str> = subset_name.split('_').collect();
+    let enum_words: Vec<&str> = enum_name.split('_').collect();
+    if let Some(position) = words
+        .windows(enum_words.len())
+        .position(|window| window == enum_words.as_slice())
+    {
+        words.drain(position..position + enum_words.len());
+    }
+    let method_name = words.join("_");
+
+    if method_name.is_empty() || method_name == "kind" {
+        let msg = format!(
+            "Can't derive the method name of subset {subset_ident} from its name, specify it with `{subset_ident}(method = ..) = [..]`"
+        );
+        return Err(syn::Error::new(subset_ident.span(), msg));
+    }
+    Ok(format_ident!("{method_name}"))
+}
+
+/// Build an additional kind type: every variant of the main enum is either mapped to the kind variant
+/// with the same name or, if `group(...)` is specified, to the group it belongs to.
+fn parse_secondary_kind(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    variants: &[Variant],
+    kinded_attrs: KindedAttributes,
+    span: Span,
+) -> Result<SecondaryKind, syn::Error> {
+    let unit_variant = |ident: &Ident| Variant::unit(ident.clone());
+
+    let (kind_variants, variant_kinds) = match kinded_attrs.groups {
+        None => {
+            let idents: Vec<Ident> = variants.iter().map(|v| v.ident.clone()).collect();
+            (idents.iter().map(unit_variant).collect(), idents)
+        }
+        Some(ref groups) => {
+            let mut group_names: Vec<&Ident> = Vec::new();
+            for group in groups {
+                if group_names.contains(&&group.ident) {
+                    let msg = format!("Duplicated group: {}", group.ident);
+                    return Err(syn::Error::new(group.ident.span(), msg));
+                }
+                group_names.push(&group.ident);
+                if let Some(unknown) = group
+                    .variants
+                    .iter()
+                    .find(|ident| !variants.iter().any(|v| &v.ident == *ident))
+                {
+                    let msg = format!("Unknown variant in group: {unknown}");
+                    return Err(syn::Error::new(unknown.span(), msg));
+                }
+            }
+
+            let mut variant_kinds = Vec::new();
+            for variant in variants {
+                let mut containing_groups = groups
+                    .iter()
+                    .filter(|group| group.variants.contains(&variant.ident));
+                let Some(group) = containing_groups.next() else {
+                    let msg = format!("Variant {} is not in any group", variant.ident);
+                    return Err(syn::Error::new(span, msg));
+                };
+                if let Some(other_group) = containing_groups.next() {
+                    let msg = format!(
+                        "Variant {} is in multiple groups: {} and {}",
+                        variant.ident, group.ident, other_group.ident
+                    );
+                    return Err(syn::Error::new(other_group.ident.span(), msg));
+                }
+                variant_kinds.push(group.ident.clone());
+            }
+            (
+                group_names.into_iter().map(unit_variant).collect(),
+                variant_kinds,
+            )
+        }
+    };
+
+    let meta = Meta {
+        vis: vis.clone(),
+        ident: ident.clone(),
+        generics: generics.clone(),
+        variants: kind_variants,
+        extra_kinds: Vec::new(),
+        kinded_attrs,
+        secondary_kinds: Vec::new(),
+        subsets: Vec::new(),
+    };
+    Ok(SecondaryKind {
+        meta,
+        variant_kinds,
+    })
+}
+
+/// Get the path of the macro exported by the derive of the flattened enum.
+/// The macro is imported under the name of the enum, so it's the type path without generic arguments.
+pub fn flatten_macro_path(ty: &Type) -> Option<Path> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let mut path = type_path.path.clone();
+    path.segments.last_mut()?.arguments = PathArguments::None;
+    Some(path)
+}
+
+/// Provide the kinds of the inner enum, received from its exported macro, to the flattened variant.
+pub fn resolve_flatten(
+    meta: &mut Meta,
+    variant_name: &Ident,
+    inner_variants: Vec<Ident>,
+) -> Result<(), syn::Error> {
+    let variant = meta
+        .variants
+        .iter_mut()
+        .find(|variant| &variant.ident == variant_name)
+        .ok_or_else(|| syn::Error::new(variant_name.span(), "Unknown flatten variant"))?;
+
+    let kind_variants = inner_variants
+        .iter()
+        .map(|inner_variant| Variant::unit(format_ident!("{}{}", variant_name, inner_variant)))
+        .collect();
+
+    variant.flatten = Some(Flatten {
+        inner_variants: Some(inner_variants),
+        kind_variants,
+    });
+    Ok(())
+}
+
+fn parse_variant(variant: &syn::Variant) -> Result<Variant, syn::Error> {
+    let VariantKindedAttrs {
+        rename,
+        attrs,
+        default_with,
+        kind_with,
+        nested,
+        flatten,
+        as_kind,
+        categories,
+        implies,
+        conflicts,
+        transitions,
+    } = parse_variant_kinded_attrs(&variant.attrs)?;
+    Ok(Variant {
         ident: variant.ident.clone(),
         fields_type: parse_fields_type(&variant.fields),
+        fields: variant
+            .fields
+            .iter()
+            .map(parse_field)
+            .collect::<Result<_, _>>()?,
         rename,
         attrs,
-    }
+        default_with,
+        kind_with,
+        nested,
+        flatten: flatten.then(Flatten::default),
+        as_kind,
+        categories,
+        implies,
+        conflicts,
+        transitions,
+    })
 }
 
 /// Parsed variant-level #[kinded(...)] attributes
+#[derive(Default)]
 struct VariantKindedAttrs {
     rename: Option<String>,
     attrs: Vec<SynMeta>,
+    default_with: Option<Path>,
+    kind_with: Option<Path>,
+    nested: bool,
+    flatten: bool,
+    as_kind: Option<Ident>,
+    categories: Vec<LitStr>,
+    implies: Vec<Ident>,
+    conflicts: Vec<Ident>,
+    transitions: Vec<Ident>,
 }
 
 /// Parse all #[kinded(...)] attributes on a variant.
 /// Handles combined attributes like #[kinded(rename = "...", attrs(...))]
-fn parse_variant_kinded_attrs(attrs: &[Attribute]) -> VariantKindedAttrs {
-    let mut result = VariantKindedAttrs {
-        rename: None,
-        attrs: Vec::new(),
-    };
+fn parse_variant_kinded_attrs(attrs: &[Attribute]) -> Result<VariantKindedAttrs, syn::Error> {
+    let mut result = VariantKindedAttrs::default();
 
     for attr in attrs {
         if attr.path().is_ident("kinded") {
-            let _ = attr.parse_args_with(|input: ParseStream| {
+            attr.parse_args_with(|input: ParseStream| {
                 while !input.is_empty() {
-                    let attr_name: Ident = input.parse()?;
+                    // `as` is a keyword, so it has to be parsed with `parse_any`
+                    let attr_name: Ident = input.call(Ident::parse_any)?;
 
                     if attr_name == "rename" {
                         let _: Token!(=) = input.parse()?;
@@ -75,8 +1071,56 @@ fn parse_variant_kinded_attrs(attrs: &[Attribute]) -> VariantKindedAttrs {
                         parenthesized!(content in input);
                         let parsed_attrs = content.parse_terminated(SynMeta::parse, Token![,])?;
                         result.attrs.extend(parsed_attrs);
+                    } else if attr_name == "default_with" {
+                        let _: Token!(=) = input.parse()?;
+                        let path: Path = input.parse()?;
+                        result.default_with = Some(path);
+                    } else if attr_name == "kind_with" {
+                        let _: Token!(=) = input.parse()?;
+                        let path: Path = input.parse()?;
+                        result.kind_with = Some(path);
+                    } else if attr_name == "nested" {
+                        result.nested = true;
+                    } else if attr_name == "flatten" {
+                        result.flatten = true;
+                    } else if attr_name == "as" {
+                        let _: Token!(=) = input.parse()?;
+                        let kind_variant: Ident = input.parse()?;
+                        result.as_kind = Some(kind_variant);
+                    } else if attr_name == "category" {
+                        let _: Token!(=) = input.parse()?;
+                        let category: LitStr = input.parse()?;
+                        if result
+                            .categories
+                            .iter()
+                            .any(|c| c.value() == category.value())
+                        {
+                            let msg = format!("Duplicated category: \"{}\"", category.value());
+                            return Err(syn::Error::new(category.span(), msg));
+                        }
+                        result.categories.push(category);
+                    } else if attr_name == "implies" {
+                        let implies_input;
+                        parenthesized!(implies_input in input);
+                        let parsed_idents =
+                            implies_input.parse_terminated(Ident::parse, Token![,])?;
+                        result.implies.extend(parsed_idents);
+                    } else if attr_name == "conflicts" {
+                        let conflicts_input;
+                        parenthesized!(conflicts_input in input);
+                        let parsed_idents =
+                            conflicts_input.parse_terminated(Ident::parse, Token![,])?;
+                        result.conflicts.extend(parsed_idents);
+                    } else if attr_name == "transitions" {
+                        let transitions_input;
+                        parenthesized!(transitions_input in input);
+                        let parsed_idents =
+                            transitions_input.parse_terminated(Ident::parse, Token![,])?;
+                        result.transitions.extend(parsed_idents);
+                    } else {
+                        let msg = format!("Unknown variant attribute: {attr_name}");
+                        return Err(syn::Error::new(attr_name.span(), msg));
                     }
-                    // Ignore unknown attributes at variant level
 
                     // Parse `,` if not at end
                     if !input.is_empty() {
@@ -84,21 +1128,11 @@ fn parse_variant_kinded_attrs(attrs: &[Attribute]) -> VariantKindedAttrs {
                     }
                 }
                 Ok(())
-            });
+            })?;
         }
     }
 
-    result
-}
-
-/// Find `#[kinded(rename = "...")]` attribute on a variant and extract the rename value.
-fn find_variant_kinded_rename(attrs: &[Attribute]) -> Option<String> {
-    parse_variant_kinded_attrs(attrs).rename
-}
-
-/// Find `#[kinded(attrs(...))]` attribute on a variant and extract the attrs.
-fn find_variant_kinded_attrs(attrs: &[Attribute]) -> Vec<SynMeta> {
-    parse_variant_kinded_attrs(attrs).attrs
+    Ok(result)
 }
 
 fn parse_fields_type(fields: &syn::Fields) -> FieldsType {
@@ -109,28 +1143,96 @@ fn parse_fields_type(fields: &syn::Fields) -> FieldsType {
     }
 }
 
-/// Find `#[kinded(..)]` attribute on the enum.
-fn find_kinded_attr(input: &DeriveInput) -> Result<Option<&Attribute>, syn::Error> {
-    let kinded_attrs: Vec<_> = input
+fn parse_field(field: &syn::Field) -> Result<Field, syn::Error> {
+    let mut keep = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("kinded") {
+            attr.parse_args_with(|input: ParseStream| {
+                while !input.is_empty() {
+                    let attr_name: Ident = input.parse()?;
+                    if attr_name == "keep" {
+                        keep = true;
+                    } else {
+                        let msg = format!("Unknown field attribute: {attr_name}");
+                        return Err(syn::Error::new(attr_name.span(), msg));
+                    }
+
+                    // Parse `,` if not at end
+                    if !input.is_empty() {
+                        let _: Token![,] = input.parse()?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(Field {
+        ident: field.ident.clone(),
+        ty: field.ty.clone(),
+        keep,
+    })
+}
+
+/// Parse `#[kinded(..)]` attributes on the enum: the attribute without `method` configures
+/// the primary kind, every attribute with `method` defines an additional kind type.
+fn parse_enum_kinded_attrs(
+    input: &DeriveInput,
+) -> Result<(KindedAttributes, Vec<(Span, KindedAttributes)>), syn::Error> {
+    let mut primary: Option<KindedAttributes> = None;
+    let mut secondary = Vec::new();
+
+    for attr in input
         .attrs
         .iter()
-        .filter(|&attr| attr.path().is_ident("kinded"))
-        .collect();
-
-    if kinded_attrs.len() > 1 {
-        let &attr = kinded_attrs.last().unwrap();
+        .filter(|attr| attr.path().is_ident("kinded"))
+    {
         let span = attr.span();
-        let msg = "Multiple #[kinded(..)] attributes are not allowed.";
-        Err(syn::Error::new(span, msg))
-    } else {
-        let maybe_kinded_attr = kinded_attrs.into_iter().next();
-        Ok(maybe_kinded_attr)
+        let kinded_attrs: KindedAttributes = syn::parse2(attr.to_token_stream())?;
+        // Only the attributes with both `kind = ..` and `method = ..` define additional kind types
+        let is_secondary = match (&kinded_attrs.kind, &kinded_attrs.method) {
+            (Some(_), Some(method)) => {
+                if method == "kind" {
+                    let msg = "method `kind` is reserved for the primary kind";
+                    return Err(syn::Error::new(method.span(), msg));
+                }
+                true
+            }
+            (None, Some(method)) => {
+                let msg = "method requires kind, because it defines an additional kind type, e.g. `#[kinded(kind = DrinkCategory, method = category)]`";
+                return Err(syn::Error::new(method.span(), msg));
+            }
+            (_, None) => false,
+        };
+        if !is_secondary {
+            if kinded_attrs.groups.is_some() {
+                let msg =
+                    "group requires kind and method, because it defines an additional kind type";
+                return Err(syn::Error::new(span, msg));
+            }
+            if primary.is_some() {
+                let msg = "Multiple #[kinded(..)] attributes are not allowed, unless they define additional kind types with `kind = ..` and `method = ..`.";
+                return Err(syn::Error::new(span, msg));
+            }
+            primary = Some(kinded_attrs);
+        } else {
+            if let Some(attr_name) = kinded_attrs.primary_only_attribute() {
+                let msg = format!(
+                    "{attr_name} is supported only by the primary kind (the #[kinded(..)] attribute without method)"
+                );
+                return Err(syn::Error::new(span, msg));
+            }
+            secondary.push((span, kinded_attrs));
+        }
     }
+
+    Ok((primary.unwrap_or_default(), secondary))
 }
 
 impl Parse for KindedAttributes {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut kinded_attrs = KindedAttributes::default();
+        let mut payload_derive_span = None;
 
         // Unwrap the irrelevant part and reassign input to the relevant input:
         //
@@ -158,6 +1260,33 @@ impl Parse for KindedAttributes {
                     let msg = format!("Duplicated attribute: {attr_name}");
                     return Err(syn::Error::new(attr_name.span(), msg));
                 }
+            } else if attr_name == "kind_of" {
+                let _: Token!(=) = input.parse()?;
+                let kind_of: Path = input.parse()?;
+                if kinded_attrs.kind_of.is_none() {
+                    kinded_attrs.kind_of = Some(kind_of);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "remote" {
+                let _: Token!(=) = input.parse()?;
+                let remote: Path = input.parse()?;
+                if kinded_attrs.remote.is_none() {
+                    kinded_attrs.remote = Some(remote);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "other" {
+                let _: Token!(=) = input.parse()?;
+                let other: Ident = input.parse()?;
+                if kinded_attrs.other.is_none() {
+                    kinded_attrs.other = Some(other);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
             } else if attr_name == "derive" {
                 let derive_input;
                 parenthesized!(derive_input in input);
@@ -169,6 +1298,17 @@ impl Parse for KindedAttributes {
                     let msg = format!("Duplicated attribute: {attr_name}");
                     return Err(syn::Error::new(attr_name.span(), msg));
                 }
+            } else if attr_name == "extra_kinds" {
+                let extra_kinds_input;
+                parenthesized!(extra_kinds_input in input);
+                let parsed_idents = extra_kinds_input.parse_terminated(Ident::parse, Token![,])?;
+                let extra_kinds: Vec<Ident> = parsed_idents.into_iter().collect();
+                if kinded_attrs.extra_kinds.is_none() {
+                    kinded_attrs.extra_kinds = Some(extra_kinds);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
             } else if attr_name == "skip_derive" {
                 let skip_input;
                 parenthesized!(skip_input in input);
@@ -192,59 +1332,226 @@ impl Parse for KindedAttributes {
                         }
                     }
                 }
-
-                if kinded_attrs.skip_derive.is_none() {
-                    kinded_attrs.skip_derive = Some(traits);
+
+                if kinded_attrs.skip_derive.is_none() {
+                    kinded_attrs.skip_derive = Some(traits);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "display" {
+                let _: Token!(=) = input.parse()?;
+                let case_lit_str: LitStr = input.parse()?;
+                let case = match case_lit_str.value().as_ref() {
+                    "snake_case" => DisplayCase::Snake,
+                    "camelCase" => DisplayCase::Camel,
+                    "PascalCase" => DisplayCase::Pascal,
+                    "SCREAMING_SNAKE_CASE" => DisplayCase::ScreamingSnake,
+                    "kebab-case" => DisplayCase::Kebab,
+                    "SCREAMING-KEBAB-CASE" => DisplayCase::ScreamingKebab,
+                    "Title Case" => DisplayCase::Title,
+                    "lowercase" => DisplayCase::Lower,
+                    "UPPERCASE" => DisplayCase::Upper,
+                    _ => {
+                        let valid_values = [
+                            "snake_case",
+                            "camelCase",
+                            "PascalCase",
+                            "SCREAMING_SNAKE_CASE",
+                            "kebab-case",
+                            "SCREAMING-KEBAB-CASE",
+                            "Title Case",
+                            "lowercase",
+                            "UPPERCASE",
+                        ]
+                        .map(|value| format!(r#""{value}""#))
+                        .join(", ");
+                        let given_value = format!(r#""{}""#, case_lit_str.value());
+                        let msg = format!(
+                            "Invalid value for display: {given_value}\nValid values are: {valid_values}"
+                        );
+                        return Err(syn::Error::new(case_lit_str.span(), msg));
+                    }
+                };
+                if kinded_attrs.display.is_none() {
+                    kinded_attrs.display = Some(case);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "attrs" {
+                let derive_input;
+                parenthesized!(derive_input in input);
+
+                let parsed_attr = derive_input.parse_terminated(SynMeta::parse, Token![,])?;
+                kinded_attrs.meta_attrs = Some(parsed_attr.into_iter().collect());
+            } else if attr_name == "accessors" {
+                if !kinded_attrs.accessors {
+                    kinded_attrs.accessors = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "ref_enum" {
+                if !kinded_attrs.ref_enum {
+                    kinded_attrs.ref_enum = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "mut_enum" {
+                if !kinded_attrs.mut_enum {
+                    kinded_attrs.mut_enum = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "parts" {
+                if !kinded_attrs.parts {
+                    kinded_attrs.parts = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "payload_structs" {
+                if !kinded_attrs.payload_structs {
+                    kinded_attrs.payload_structs = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "payload_derive" {
+                let derive_input;
+                parenthesized!(derive_input in input);
+                let parsed_traits = derive_input.parse_terminated(Path::parse, Token![,])?;
+                let traits: Vec<Path> = parsed_traits.into_iter().collect();
+                if kinded_attrs.payload_derive.is_none() {
+                    payload_derive_span = Some(attr_name.span());
+                    kinded_attrs.payload_derive = Some(traits);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "export" {
+                if !kinded_attrs.export {
+                    kinded_attrs.export = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "ignore_case" {
+                if !kinded_attrs.ignore_case {
+                    kinded_attrs.ignore_case = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "markers" {
+                if !kinded_attrs.markers {
+                    kinded_attrs.markers = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "default_values" {
+                if !kinded_attrs.default_values {
+                    kinded_attrs.default_values = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "visitor" {
+                let mut visitor = Visitor::default();
+                if input.peek(token::Paren) {
+                    let visitor_input;
+                    parenthesized!(visitor_input in input);
+                    while !visitor_input.is_empty() {
+                        let option_name: Ident = visitor_input.parse()?;
+                        if option_name == "fallback" {
+                            visitor.fallback = true;
+                        } else {
+                            let msg = format!("Unknown option for visitor: {option_name}");
+                            return Err(syn::Error::new(option_name.span(), msg));
+                        }
+                        if !visitor_input.is_empty() {
+                            let _comma: Token![,] = visitor_input.parse()?;
+                        }
+                    }
+                }
+                if kinded_attrs.visitor.is_none() {
+                    kinded_attrs.visitor = Some(visitor);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "method" {
+                let _: Token!(=) = input.parse()?;
+                let method: Ident = input.parse()?;
+                if kinded_attrs.method.is_none() {
+                    kinded_attrs.method = Some(method);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "group" {
+                let group_input;
+                parenthesized!(group_input in input);
+                let parsed_groups = group_input.parse_terminated(KindGroup::parse, Token![,])?;
+                let groups: Vec<KindGroup> = parsed_groups.into_iter().collect();
+                if let Some(method) = groups.iter().find_map(|group| group.method.as_ref()) {
+                    let msg = "method is supported only by subsets, groups are returned by the method of the kind type";
+                    return Err(syn::Error::new(method.span(), msg));
+                }
+                if kinded_attrs.groups.is_none() {
+                    kinded_attrs.groups = Some(groups);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "subset" {
+                let subset_input;
+                parenthesized!(subset_input in input);
+                let parsed_subsets = subset_input.parse_terminated(KindGroup::parse, Token![,])?;
+                let subsets: Vec<KindGroup> = parsed_subsets.into_iter().collect();
+                if kinded_attrs.subsets.is_none() {
+                    kinded_attrs.subsets = Some(subsets);
                 } else {
                     let msg = format!("Duplicated attribute: {attr_name}");
                     return Err(syn::Error::new(attr_name.span(), msg));
                 }
-            } else if attr_name == "display" {
-                let _: Token!(=) = input.parse()?;
-                let case_lit_str: LitStr = input.parse()?;
-                let case = match case_lit_str.value().as_ref() {
-                    "snake_case" => DisplayCase::Snake,
-                    "camelCase" => DisplayCase::Camel,
-                    "PascalCase" => DisplayCase::Pascal,
-                    "SCREAMING_SNAKE_CASE" => DisplayCase::ScreamingSnake,
-                    "kebab-case" => DisplayCase::Kebab,
-                    "SCREAMING-KEBAB-CASE" => DisplayCase::ScreamingKebab,
-                    "Title Case" => DisplayCase::Title,
-                    "lowercase" => DisplayCase::Lower,
-                    "UPPERCASE" => DisplayCase::Upper,
-                    _ => {
-                        let valid_values = [
-                            "snake_case",
-                            "camelCase",
-                            "PascalCase",
-                            "SCREAMING_SNAKE_CASE",
-                            "kebab-case",
-                            "SCREAMING-KEBAB-CASE",
-                            "Title Case",
-                            "lowercase",
-                            "UPPERCASE",
-                        ]
-                        .map(|value| format!(r#""{value}""#))
-                        .join(", ");
-                        let given_value = format!(r#""{}""#, case_lit_str.value());
-                        let msg = format!(
-                            "Invalid value for display: {given_value}\nValid values are: {valid_values}"
-                        );
-                        return Err(syn::Error::new(case_lit_str.span(), msg));
+            } else if attr_name == "predicates" {
+                let mut predicates = Predicates::default();
+                if input.peek(token::Paren) {
+                    let predicates_input;
+                    parenthesized!(predicates_input in input);
+                    while !predicates_input.is_empty() {
+                        let option_name: Ident = predicates_input.parse()?;
+                        if option_name == "prefix" {
+                            let _: Token!(=) = predicates_input.parse()?;
+                            let prefix: LitStr = predicates_input.parse()?;
+                            if syn::parse_str::<Ident>(&format!("{}x", prefix.value())).is_err() {
+                                let msg = format!(
+                                    "Invalid prefix for predicates: \"{}\"",
+                                    prefix.value()
+                                );
+                                return Err(syn::Error::new(prefix.span(), msg));
+                            }
+                            predicates.prefix = prefix.value();
+                        } else {
+                            let msg = format!("Unknown option for predicates: {option_name}");
+                            return Err(syn::Error::new(option_name.span(), msg));
+                        }
+                        if !predicates_input.is_empty() {
+                            let _comma: Token![,] = predicates_input.parse()?;
+                        }
                     }
-                };
-                if kinded_attrs.derive.is_none() {
-                    kinded_attrs.display = Some(case);
+                }
+                if kinded_attrs.predicates.is_none() {
+                    kinded_attrs.predicates = Some(predicates);
                 } else {
                     let msg = format!("Duplicated attribute: {attr_name}");
                     return Err(syn::Error::new(attr_name.span(), msg));
                 }
-            } else if attr_name == "attrs" {
-                let derive_input;
-                parenthesized!(derive_input in input);
-
-                let parsed_attr = derive_input.parse_terminated(SynMeta::parse, Token![,])?;
-                kinded_attrs.meta_attrs = Some(parsed_attr.into_iter().collect());
             } else {
                 let msg = format!("Unknown attribute: {attr_name}");
                 return Err(syn::Error::new(attr_name.span(), msg));
@@ -256,10 +1563,46 @@ impl Parse for KindedAttributes {
             }
         }
 
+        if let Some(span) = payload_derive_span
+            && !kinded_attrs.parts
+            && !kinded_attrs.payload_structs
+        {
+            let msg = "payload_derive requires parts or payload_structs";
+            return Err(syn::Error::new(span, msg));
+        }
+
         Ok(kinded_attrs)
     }
 }
 
+impl Parse for KindGroup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        let method = if input.peek(token::Paren) {
+            let options_input;
+            parenthesized!(options_input in input);
+            let option_name: Ident = options_input.parse()?;
+            if option_name != "method" {
+                let msg = format!("Unknown option: {option_name}. Allowed options: method");
+                return Err(syn::Error::new(option_name.span(), msg));
+            }
+            let _: Token!(=) = options_input.parse()?;
+            Some(options_input.parse()?)
+        } else {
+            None
+        };
+        let _: Token!(=) = input.parse()?;
+        let variants_input;
+        bracketed!(variants_input in input);
+        let parsed_variants = variants_input.parse_terminated(Ident::parse, Token![,])?;
+        Ok(Self {
+            ident,
+            variants: parsed_variants.into_iter().collect(),
+            method,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -348,4 +1691,946 @@ mod tests {
         let err = result.unwrap_err().to_string();
         assert!(err.contains("Duplicated attribute"));
     }
+
+    #[test]
+    fn parse_display_after_derive() {
+        let attrs = parse_kinded_attrs(quote! {
+            #[kinded(derive(Hash), display = "snake_case")]
+        })
+        .unwrap();
+        assert!(attrs.derive.is_some());
+        assert!(matches!(attrs.display, Some(DisplayCase::Snake)));
+    }
+
+    #[test]
+    fn parse_display_duplicated() {
+        let result = parse_kinded_attrs(quote! {
+            #[kinded(display = "snake_case", display = "camelCase")]
+        });
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Duplicated attribute: display"));
+    }
+
+    #[test]
+    fn parse_ignore_case() {
+        let attrs = parse_kinded_attrs(quote! { #[kinded(ignore_case)] }).unwrap();
+        assert!(attrs.ignore_case);
+
+        let attrs = parse_kinded_attrs(quote! { #[kinded(display = "snake_case")] }).unwrap();
+        assert!(!attrs.ignore_case);
+    }
+
+    #[test]
+    fn parse_visitor() {
+        let attrs = parse_kinded_attrs(quote! { #[kinded(visitor)] }).unwrap();
+        assert!(!attrs.visitor.unwrap().fallback);
+
+        let attrs = parse_kinded_attrs(quote! { #[kinded(visitor(fallback))] }).unwrap();
+        assert!(attrs.visitor.unwrap().fallback);
+    }
+
+    #[test]
+    fn parse_visitor_unknown_option() {
+        let result = parse_kinded_attrs(quote! { #[kinded(visitor(strict))] });
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Unknown option for visitor: strict"));
+    }
+
+    #[test]
+    fn parse_predicates_default_prefix() {
+        let attrs = parse_kinded_attrs(quote! { #[kinded(predicates)] }).unwrap();
+        assert_eq!(attrs.predicates.unwrap().prefix, "is_");
+    }
+
+    #[test]
+    fn parse_predicates_custom_prefix() {
+        let attrs =
+            parse_kinded_attrs(quote! { #[kinded(predicates(prefix = "is_kind_"))] }).unwrap();
+        assert_eq!(attrs.predicates.unwrap().prefix, "is_kind_");
+    }
+
+    #[test]
+    fn parse_predicates_invalid_prefix() {
+        let result = parse_kinded_attrs(quote! { #[kinded(predicates(prefix = "is-"))] });
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Invalid prefix for predicates"));
+    }
+
+    #[test]
+    fn parse_predicates_unknown_option() {
+        let result = parse_kinded_attrs(quote! { #[kinded(predicates(suffix = "_kind"))] });
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Unknown option for predicates: suffix"));
+    }
+
+    #[test]
+    fn parse_payload_derive_requires_payload_types() {
+        let result = parse_kinded_attrs(quote! { #[kinded(payload_derive(Debug))] });
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("payload_derive requires parts or payload_structs"));
+
+        let attrs =
+            parse_kinded_attrs(quote! { #[kinded(payload_structs, payload_derive(Debug))] })
+                .unwrap();
+        assert!(attrs.payload_structs);
+        assert_eq!(attrs.payload_derive.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn parse_keep_field() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(skip_derive(Kind, FromStr))]
+            enum Event {
+                UserCreated {
+                    #[kinded(keep)]
+                    tenant_id: u32,
+                    name: String,
+                },
+                Ping,
+            }
+        };
+        let meta = parse_derive_input(input).unwrap();
+        let fields = &meta.variants[0].fields;
+        assert!(fields[0].keep);
+        assert!(!fields[1].keep);
+        assert!(meta.has_kept_fields());
+    }
+
+    #[test]
+    fn parse_keep_unknown_field_attribute() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Event {
+                UserCreated(#[kinded(hold)] u32),
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(err.contains("Unknown field attribute: hold"));
+    }
+
+    #[test]
+    fn parse_keep_incompatible() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Event<T> {
+                UserCreated(#[kinded(keep)] u32, T),
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(err.contains("keep is not supported for enums with generics"));
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(markers)]
+            enum Event {
+                UserCreated(#[kinded(keep)] u32),
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(err.contains("keep is not compatible with markers"));
+
+        for attrs in [
+            quote!(),
+            quote!(#[kinded(skip_derive(Kind))]),
+            quote!(#[kinded(skip_derive(FromStr))]),
+        ] {
+            let input: DeriveInput = syn::parse_quote! {
+                #attrs
+                enum Event {
+                    UserCreated(#[kinded(keep)] u32),
+                }
+            };
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(err.contains("keep requires #[kinded(skip_derive(Kind, FromStr))]"));
+        }
+    }
+
+    #[test]
+    fn parse_kind_with_and_extra_kinds() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(extra_kinds(Int, Float))]
+            enum Value {
+                #[kinded(kind_with = number_kind)]
+                Number(f64),
+                Null,
+            }
+        };
+        let meta = parse_derive_input(input).unwrap();
+        assert!(meta.has_kind_with());
+        let kind_variants: Vec<String> = meta
+            .kind_variants()
+            .map(|variant| variant.ident.to_string())
+            .collect();
+        assert_eq!(kind_variants, ["Null", "Int", "Float"]);
+    }
+
+    #[test]
+    fn parse_extra_kinds_duplicated() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(extra_kinds(Null))]
+            enum Value {
+                Null,
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(err.contains("Duplicated kind variant: Null"));
+    }
+
+    #[test]
+    fn parse_kind_with_incompatible() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(default_values, extra_kinds(Int))]
+            enum Value {
+                Null,
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(
+            err.contains(
+                "default_values is not compatible with kind_with, flatten and extra_kinds"
+            )
+        );
+
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(markers)]
+            enum Value {
+                #[kinded(kind_with = number_kind)]
+                Number(f64),
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(err.contains("kind_with is not compatible with markers"));
+    }
+
+    #[test]
+    fn parse_nested_requires_single_field() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Event {
+                #[kinded(nested)]
+                Drink(Drink, u32),
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(err.contains("nested variant must have exactly one field"));
+    }
+
+    #[test]
+    fn parse_flatten() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Event {
+                #[kinded(flatten)]
+                Drink(drinks::Drink),
+                Ping,
+            }
+        };
+        let mut meta = parse_derive_input(input).unwrap();
+        let variant = meta.unresolved_flatten().unwrap();
+        let macro_path = flatten_macro_path(&variant.fields[0].ty).unwrap();
+        assert_eq!(macro_path.to_token_stream().to_string(), "drinks :: Drink");
+
+        let inner_variants = vec![format_ident!("Mate"), format_ident!("Coffee")];
+        resolve_flatten(&mut meta, &format_ident!("Drink"), inner_variants).unwrap();
+        assert!(meta.unresolved_flatten().is_none());
+        let kind_variants: Vec<String> = meta
+            .kind_variants()
+            .map(|variant| variant.ident.to_string())
+            .collect();
+        assert_eq!(kind_variants, ["DrinkMate", "DrinkCoffee", "Ping"]);
+    }
+
+    #[test]
+    fn parse_flatten_requires_single_field() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Event {
+                #[kinded(flatten)]
+                Drink,
+            }
+        };
+        let err = parse_derive_input(input).unwrap_err().to_string();
+        assert!(err.contains("flatten variant must have exactly one field"));
+    }
+
+    #[test]
+    fn parse_secondary_kinds() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(display = "snake_case")]
+            #[kinded(kind = DrinkCategory, method = category, group(Hot = [Coffee, Tea], Cold = [Mate]))]
+            #[kinded(kind = DrinkName, method = name)]
+            enum Drink {
+                Mate,
+                Coffee(String),
+                Tea { variety: String },
+            }
+        };
+        let meta = parse_derive_input(input).unwrap();
+        assert!(meta.kinded_attrs.display.is_some());
+        assert_eq!(meta.secondary_kinds.len(), 2);
+
+        let category = &meta.secondary_kinds[0];
+        assert_eq!(category.meta.kind_name(), "DrinkCategory");
+        assert_eq!(category.meta.kind_method(), "category");
+        let kind_variants: Vec<String> = category
+            .meta
+            .kind_variants()
+            .map(|variant| variant.ident.to_string())
+            .collect();
+        assert_eq!(kind_variants, ["Hot", "Cold"]);
+        assert_eq!(category.variant_kinds, ["Cold", "Hot", "Hot"]);
+
+        let name = &meta.secondary_kinds[1];
+        assert_eq!(name.variant_kinds, ["Mate", "Coffee", "Tea"]);
+    }
+
+    #[test]
+    fn parse_secondary_kinds_invalid() {
+        let cases = [
+            (
+                quote! {
+                    #[kinded(kind = DrinkKind)]
+                    #[kinded(kind = DrinkCategory)]
+                    enum Drink { Mate }
+                },
+                "Multiple #[kinded(..)] attributes are not allowed",
+            ),
+            (
+                quote! {
+                    #[kinded(method = category)]
+                    enum Drink { Mate }
+                },
+                "method requires kind",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = DrinkCategory, method = category, predicates)]
+                    enum Drink { Mate }
+                },
+                "predicates is supported only by the primary kind",
+            ),
+            (
+                quote! {
+                    #[kinded(display = "snake_case")]
+                    #[kinded(method = category, display = "UPPERCASE")]
+                    enum Drink { Mate }
+                },
+                "method requires kind",
+            ),
+            (
+                quote! {
+                    #[kinded(group(Hot = [Mate]))]
+                    enum Drink { Mate }
+                },
+                "group requires kind and method",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = DrinkCategory, group(Hot = [Mate]))]
+                    enum Drink { Mate }
+                },
+                "group requires kind and method",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = DrinkKind, method = category)]
+                    enum Drink { Mate }
+                },
+                "Duplicated kind type: DrinkKind",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = DrinkCategory, method = category, group(Hot = [Coffee]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "Variant Mate is not in any group",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = DrinkCategory, method = category, group(Hot = [Mate], Cold = [Mate]))]
+                    enum Drink { Mate }
+                },
+                "Variant Mate is in multiple groups: Hot and Cold",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = DrinkCategory, method = category, group(Hot = [Tea]))]
+                    enum Drink { Mate }
+                },
+                "Unknown variant in group: Tea",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_kind_of() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(kind_of = api::EventType)]
+            enum Event {
+                Created,
+                #[kinded(as = Deleted)]
+                Removed(u32),
+            }
+        };
+        let meta = parse_derive_input(input).unwrap();
+        assert_eq!(meta.kind_name(), "EventType");
+        assert_eq!(
+            meta.kind_type().to_token_stream().to_string(),
+            "api :: EventType"
+        );
+        let kind_variants: Vec<String> = meta
+            .variants
+            .iter()
+            .map(|variant| variant.kind_variant_ident().to_string())
+            .collect();
+        assert_eq!(kind_variants, ["Created", "Deleted"]);
+    }
+
+    #[test]
+    fn parse_kind_of_invalid() {
+        let cases = [
+            (
+                quote! {
+                    #[kinded(kind_of = EventType, display = "snake_case")]
+                    enum Event { Created }
+                },
+                "kind_of is not compatible with display",
+            ),
+            (
+                quote! {
+                    #[kinded(kind_of = EventType, ignore_case)]
+                    enum Event { Created }
+                },
+                "kind_of is not compatible with ignore_case",
+            ),
+            (
+                quote! {
+                    enum Event {
+                        #[kinded(as = Deleted)]
+                        Removed,
+                    }
+                },
+                "as requires kind_of attribute on the enum",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = EventCategory, method = category, kind_of = EventType)]
+                    enum Event { Created }
+                },
+                "kind_of is supported only by the primary kind",
+            ),
+            (
+                quote! {
+                    #[kinded(kind_of = EventType, markers)]
+                    enum Event {
+                        Created(u32),
+                        #[kinded(as = Created)]
+                        Imported(u32),
+                    }
+                },
+                "markers require a distinct kind per variant, but Created and Imported are both mapped to Created",
+            ),
+            (
+                quote! {
+                    #[kinded(kind_of = EventType, skip_derive(Kinded), markers)]
+                    enum Event { Created(u32) }
+                },
+                "skip_derive(Kinded) is not compatible with markers",
+            ),
+            (
+                quote! {
+                    #[kinded(skip_derive(Kinded), export)]
+                    enum Event { Created(u32) }
+                },
+                "skip_derive(Kinded) is not compatible with export",
+            ),
+            (
+                quote! {
+                    #[kinded(skip_derive(Kind), markers)]
+                    enum Event { Created(u32) }
+                },
+                "skip_derive(Kind) is not compatible with markers",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_subset() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(subset(
+                HotDrinkKind = [Coffee, Tea],
+                Cold = [Mate],
+                Drinkable = [Mate, Tea],
+                CaffeinatedDrinks(method = caffeinated) = [Coffee],
+            ))]
+            enum Drink {
+                Mate,
+                Coffee(String),
+                Tea { variety: String },
+            }
+        };
+        let meta = parse_derive_input(input).unwrap();
+        let methods: Vec<String> = meta
+            .subsets
+            .iter()
+            .map(|subset| subset.method.to_string())
+            .collect();
+        assert_eq!(methods, ["hot_kind", "cold", "drinkable", "caffeinated"]);
+        let skip_derive = meta.subsets[0]
+            .meta
+            .kinded_attrs
+            .skip_derive
+            .as_ref()
+            .unwrap();
+        assert!(skip_derive.contains(&Trait::From));
+    }
+
+    #[test]
+    fn parse_subset_invalid() {
+        let cases = [
+            (
+                quote! {
+                    #[kinded(subset(HotDrinkKind = [Coffee, Juice]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "Unknown kind variant in subset: Juice",
+            ),
+            (
+                quote! {
+                    #[kinded(subset(HotDrinkKind = [Coffee, Coffee]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "Duplicated kind variant in subset: Coffee",
+            ),
+            (
+                quote! {
+                    #[kinded(subset(DrinkKind = [Coffee]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "Duplicated kind type: DrinkKind",
+            ),
+            (
+                quote! {
+                    #[kinded(subset(HotDrinkKind = [Coffee]))]
+                    enum Drink { Mate, Coffee { #[kinded(keep)] size: u8 } }
+                },
+                "keep is not compatible with subset",
+            ),
+            (
+                quote! {
+                    #[kinded(subset(Drink = [Coffee]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "Can't derive the method name of subset Drink from its name",
+            ),
+            (
+                quote! {
+                    #[kinded(subset(KindDrink = [Coffee]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "Can't derive the method name of subset KindDrink",
+            ),
+            (
+                quote! {
+                    #[kinded(subset(HotDrinkKind(method = kind) = [Coffee]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "method `kind` is reserved for the primary kind",
+            ),
+            (
+                quote! {
+                    #[kinded(subset(HotDrinkKind(name = hot) = [Coffee]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "Unknown option: name",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = DrinkCategory, method = category, group(Hot(method = hot) = [Coffee], Cold = [Mate]))]
+                    enum Drink { Mate, Coffee }
+                },
+                "method is supported only by subsets",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_categories() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Drink {
+                #[kinded(category = "cold")]
+                Mate,
+                #[kinded(category = "hot", category = "caffeinated")]
+                Coffee,
+                #[kinded(category = "hot")]
+                Tea,
+            }
+        };
+        let meta = parse_derive_input(input).unwrap();
+        assert_eq!(meta.category_name(), "DrinkKindCategory");
+        let categories: Vec<String> = meta.categories().iter().map(|c| c.value()).collect();
+        assert_eq!(categories, ["cold", "hot", "caffeinated"]);
+    }
+
+    #[test]
+    fn parse_categories_invalid() {
+        let cases = [
+            (
+                quote! {
+                    enum Drink {
+                        #[kinded(category = "hot", category = "hot")]
+                        Coffee,
+                    }
+                },
+                "Duplicated category: \"hot\"",
+            ),
+            (
+                quote! {
+                    enum Drink {
+                        #[kinded(category = "1st")]
+                        Coffee,
+                    }
+                },
+                "Invalid category name: \"1st\"",
+            ),
+            (
+                quote! {
+                    enum Drink {
+                        #[kinded(category = "hot drinks")]
+                        Coffee,
+                        #[kinded(category = "hot_drinks")]
+                        Tea,
+                    }
+                },
+                "Category \"hot_drinks\" conflicts with \"hot drinks\", both are named HotDrinks",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_relations_invalid() {
+        let cases = [
+            (
+                quote! {
+                    enum Permission {
+                        #[kinded(implies(Write))]
+                        Read,
+                        #[kinded(implies(Admin))]
+                        Write,
+                        #[kinded(implies(Read))]
+                        Admin,
+                    }
+                },
+                "Cyclic implication: Read -> Write -> Admin -> Read",
+            ),
+            (
+                quote! {
+                    enum Permission {
+                        #[kinded(implies(Read))]
+                        Read,
+                    }
+                },
+                "Read can't imply itself",
+            ),
+            (
+                quote! {
+                    enum Permission {
+                        #[kinded(conflicts(Execute))]
+                        Read,
+                    }
+                },
+                "Unknown kind variant: Execute",
+            ),
+            (
+                quote! {
+                    enum Permission {
+                        Read,
+                        #[kinded(conflicts(Read))]
+                        Write,
+                        #[kinded(implies(Read, Write))]
+                        Admin,
+                    }
+                },
+                "Admin implies conflicting kinds: Read conflicts with Write",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_transitions_invalid() {
+        let cases = [
+            (
+                quote! {
+                    enum Order {
+                        #[kinded(transitions(Shipped))]
+                        Pending,
+                        Paid,
+                    }
+                },
+                "Unknown kind variant: Shipped",
+            ),
+            (
+                quote! {
+                    enum Order {
+                        #[kinded(transitions(Paid, Paid))]
+                        Pending,
+                        Paid,
+                    }
+                },
+                "Duplicated transition: Paid",
+            ),
+            (
+                quote! {
+                    enum Order {
+                        #[kinded(transitions(Paid), flatten)]
+                        Pending(PendingOrder),
+                        Paid,
+                    }
+                },
+                "transitions can't be used on a flattened variant",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_kind_derive_invalid() {
+        let cases = [
+            (
+                quote! {
+                    enum Color {
+                        Red,
+                        Rgb(u8, u8, u8),
+                    }
+                },
+                "Kind can be derived only on fieldless enums, but variant Rgb has fields",
+            ),
+            (
+                quote! {
+                    struct Color;
+                },
+                "Kind can be derived only on enums",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = ColorKind)]
+                    enum Color {
+                        Red,
+                    }
+                },
+                "kind is not supported by derive(Kind)",
+            ),
+            (
+                quote! {
+                    #[kinded(predicates)]
+                    enum Color {
+                        Red,
+                    }
+                },
+                "predicates is not supported by derive(Kind)",
+            ),
+            (
+                quote! {
+                    enum Color {
+                        #[kinded(implies(Green))]
+                        Red,
+                        Green,
+                    }
+                },
+                "implies is not supported by derive(Kind)",
+            ),
+            (
+                quote! {
+                    #[kinded(skip_derive(Kinded))]
+                    enum Color {
+                        Red,
+                    }
+                },
+                "skip_derive(Kinded) is not supported by derive(Kind)",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_kind_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_remote() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[kinded(remote = serde_json::Value)]
+            enum ValueDef {
+                Null,
+                Bool(bool),
+            }
+        };
+        let meta = parse_derive_input(input).unwrap();
+        assert_eq!(meta.kind_name(), "ValueKind");
+        assert_eq!(meta.remote_trait_name().unwrap(), "ValueKinded");
+        assert_eq!(
+            meta.main_enum_with_generics().to_string(),
+            "serde_json :: Value"
+        );
+    }
+
+    #[test]
+    fn parse_remote_invalid() {
+        let cases = [
+            (
+                quote! {
+                    #[kinded(remote = serde_json::Value, accessors)]
+                    enum ValueDef { Null }
+                },
+                "remote is not compatible with accessors",
+            ),
+            (
+                quote! {
+                    #[kinded(remote = serde_json::Value)]
+                    enum ValueDef {
+                        #[kinded(kind_with = bool_kind)]
+                        Bool(bool),
+                    }
+                },
+                "remote is not compatible with kind_with",
+            ),
+            (
+                quote! {
+                    #[kinded(remote = serde_json::Value, remote = serde_json::Map)]
+                    enum ValueDef { Null }
+                },
+                "Duplicated attribute: remote",
+            ),
+            (
+                quote! {
+                    #[kinded(other = Unknown)]
+                    enum Drink { Tea }
+                },
+                "other requires remote attribute on the enum",
+            ),
+            (
+                quote! {
+                    #[kinded(remote = core::sync::atomic::Ordering)]
+                    #[non_exhaustive]
+                    enum OrderingDef { Relaxed }
+                },
+                "The remote enum is #[non_exhaustive]",
+            ),
+            (
+                quote! {
+                    #[kinded(remote = core::sync::atomic::Ordering, other = Relaxed)]
+                    #[non_exhaustive]
+                    enum OrderingDef { Relaxed }
+                },
+                "Relaxed",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_struct_delegate() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Tagged<T>(String, #[kinded(delegate)] T);
+        };
+        let delegate = parse_struct_derive_input(input).unwrap();
+        assert_eq!(delegate.ident, "Tagged");
+        assert_eq!(delegate.member, Member::from(1));
+        assert_eq!(delegate.ty.to_token_stream().to_string(), "T");
+    }
+
+    #[test]
+    fn parse_struct_delegate_invalid() {
+        let cases = [
+            (
+                quote! {
+                    struct Envelope { id: u64, body: EventBody }
+                },
+                "Kinded can be derived on a struct only when one of its fields is marked with #[kinded(delegate)]",
+            ),
+            (
+                quote! {
+                    struct Envelope {
+                        #[kinded(delegate)]
+                        header: Header,
+                        #[kinded(delegate)]
+                        body: EventBody,
+                    }
+                },
+                "Only one field can be marked with #[kinded(delegate)]",
+            ),
+            (
+                quote! {
+                    #[kinded(kind = EnvelopeKind)]
+                    struct Envelope { #[kinded(delegate)] body: EventBody }
+                },
+                "#[kinded(..)] attributes are not supported on structs",
+            ),
+            (
+                quote! {
+                    struct Envelope { #[kinded(keep)] body: EventBody }
+                },
+                "Unknown field attribute: keep",
+            ),
+        ];
+        for (tokens, expected) in cases {
+            let input: DeriveInput = syn::parse2(tokens).unwrap();
+            let err = parse_struct_derive_input(input).unwrap_err().to_string();
+            assert!(
+                err.contains(expected),
+                "{err:?} doesn't contain {expected:?}"
+            );
+        }
+    }
 }
@@ -273,6 +273,12 @@ mod kind_enum {
                 );
             }
 
+            #[test]
+            fn should_not_ignore_case_by_default() {
+                assert!("hOt_MaTe".parse::<MateKind>().is_err());
+                assert!("tErErE".parse::<MateKind>().is_err());
+            }
+
             #[test]
             fn should_distinguish_very_similar_abbreviations() {
                 #[derive(kinded::Kinded)]
@@ -307,7 +313,7 @@ mod kind_enum {
             #[test]
             fn should_apply_single_attr() {
                 // RoleKind has serde(rename_all = "camelCase") applied
-                let value = serde_json::to_value(&RoleKind::Guest).unwrap();
+                let value = serde_json::to_value(RoleKind::Guest).unwrap();
                 assert_eq!(value, json!("guest"));
             }
 
@@ -328,10 +334,10 @@ mod kind_enum {
                 }
 
                 // Test serialization with snake_case
-                let value = serde_json::to_value(&VehicleKind::SportsCar).unwrap();
+                let value = serde_json::to_value(VehicleKind::SportsCar).unwrap();
                 assert_eq!(value, json!("sports_car"));
 
-                let value = serde_json::to_value(&VehicleKind::PickupTruck).unwrap();
+                let value = serde_json::to_value(VehicleKind::PickupTruck).unwrap();
                 assert_eq!(value, json!("pickup_truck"));
 
                 // Test deserialization
@@ -365,10 +371,10 @@ mod kind_enum {
                     WildDog,
                 }
 
-                let value = serde_json::to_value(&AnimalType::DomesticCat).unwrap();
+                let value = serde_json::to_value(AnimalType::DomesticCat).unwrap();
                 assert_eq!(value, json!("DOMESTIC_CAT"));
 
-                let value = serde_json::to_value(&AnimalType::WildDog).unwrap();
+                let value = serde_json::to_value(AnimalType::WildDog).unwrap();
                 assert_eq!(value, json!("WILD_DOG"));
             }
 
@@ -389,7 +395,7 @@ mod kind_enum {
                 assert_eq!(FruitKind::GreenApple.to_string(), "green-apple");
 
                 // Serde should also use kebab-case
-                let value = serde_json::to_value(&FruitKind::RedCherry).unwrap();
+                let value = serde_json::to_value(FruitKind::RedCherry).unwrap();
                 assert_eq!(value, json!("red-cherry"));
             }
 
@@ -516,7 +522,7 @@ mod const_kind {
         }
 
         const RESULT: bool = is_active(&Status::Active);
-        assert!(RESULT);
+        const { assert!(RESULT) };
     }
 
     /// Test with generic enum
@@ -873,6 +879,7 @@ mod skip_derive {
         #[test]
         fn should_still_have_clone_copy() {
             let kind = PriorityKind::Low;
+            #[allow(clippy::clone_on_copy)]
             let cloned = kind.clone();
             let copied = kind;
             assert_eq!(cloned, copied);
@@ -937,6 +944,31 @@ mod skip_derive {
         }
     }
 
+    mod skip_kind {
+        use super::*;
+
+        // Kinded and KindedPath traits require the Kind trait, so they are skipped too
+        #[derive(Kinded)]
+        #[kinded(skip_derive(Kind), ref_enum, parts)]
+        enum Event {
+            Click { x: i32 },
+            Hover,
+        }
+
+        #[test]
+        fn should_still_have_kind_method() {
+            let event = Event::Click { x: 1 };
+            assert_eq!(event.kind(), EventKind::Click);
+            assert_eq!(event.as_ref_enum().kind(), EventKind::Click);
+            assert_eq!(EventKind::from(&event), EventKind::Click);
+        }
+
+        #[test]
+        fn should_still_have_from_str() {
+            assert_eq!("hover".parse::<EventKind>().unwrap(), EventKind::Hover);
+        }
+    }
+
     mod skip_multiple_impl_traits {
         use super::*;
 
@@ -963,6 +995,7 @@ mod skip_derive {
         #[test]
         fn should_still_have_clone_copy() {
             let kind = MinimalKind::A;
+            #[allow(clippy::clone_on_copy)]
             let cloned = kind.clone();
             let copied = kind;
             assert_eq!(cloned, copied);
@@ -1026,3 +1059,2264 @@ mod skip_derive {
         }
     }
 }
+
+mod bounded_generics {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(predicates)]
+    enum Slot<'a, T: Clone + 'a, const N: usize>
+    where
+        T: core::fmt::Debug,
+    {
+        Filled([&'a T; N]),
+        Empty,
+    }
+
+    #[test]
+    fn should_implement_kinded() {
+        let value = 1;
+        let slot: Slot<i32, 2> = Slot::Filled([&value, &value]);
+        assert_eq!(slot.kind(), SlotKind::Filled);
+        assert_eq!(Kinded::kind(&Slot::<i32, 2>::Empty), SlotKind::Empty);
+    }
+
+    #[test]
+    fn should_implement_from() {
+        let value = 1;
+        assert_eq!(SlotKind::from(&Slot::Filled([&value])), SlotKind::Filled);
+        assert_eq!(SlotKind::from(Slot::<i32, 1>::Empty), SlotKind::Empty);
+    }
+
+    #[test]
+    fn should_implement_cross_partial_eq() {
+        let value = 1;
+        assert!(Slot::Filled([&value]) == SlotKind::Filled);
+        assert!(SlotKind::Empty == Slot::<i32, 1>::Empty);
+    }
+
+    #[test]
+    fn should_implement_predicates() {
+        let value = 1;
+        assert!(Slot::Filled([&value]).is_filled());
+        assert!(Slot::<i32, 1>::Empty.is_empty());
+    }
+}
+
+mod err_variant {
+    use alloc::string::{String, ToString};
+    use kinded::Kinded;
+
+    // `Self::Err` in the generated `FromStr` would be ambiguous with the variant
+    #[derive(Kinded)]
+    enum Outcome {
+        Ok,
+        Err(String),
+    }
+
+    #[test]
+    fn should_parse_kind_with_err_variant() {
+        assert_eq!("err".parse::<OutcomeKind>().unwrap(), OutcomeKind::Err);
+        assert_eq!(Outcome::Err("burnt".to_string()).kind(), OutcomeKind::Err);
+    }
+}
+
+mod predicates {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(predicates)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        HotTea { caffeine: bool },
+    }
+
+    #[test]
+    fn should_generate_predicates_on_main_enum() {
+        let coffee = Drink::Coffee("Espresso");
+        assert!(coffee.is_coffee());
+        assert!(!coffee.is_mate());
+        assert!(!coffee.is_hot_tea());
+
+        let tea = Drink::HotTea { caffeine: true };
+        assert!(tea.is_hot_tea());
+        assert!(Drink::Mate.is_mate());
+    }
+
+    #[test]
+    fn should_generate_predicates_on_kind_enum() {
+        assert!(DrinkKind::Mate.is_mate());
+        assert!(!DrinkKind::Mate.is_coffee());
+        assert!(DrinkKind::HotTea.is_hot_tea());
+    }
+
+    #[test]
+    fn should_work_in_const_context() {
+        const IS_MATE: bool = Drink::Mate.is_mate();
+        const IS_COFFEE: bool = DrinkKind::Mate.is_coffee();
+        const { assert!(IS_MATE) };
+        const { assert!(!IS_COFFEE) };
+    }
+
+    #[test]
+    fn should_use_custom_prefix() {
+        #[derive(Kinded)]
+        #[kinded(predicates(prefix = "is_kind_"))]
+        enum Shape {
+            Circle(u32),
+            Square(u32),
+        }
+
+        impl Shape {
+            // User defined method that would clash with the default predicate name
+            fn is_circle(&self) -> &'static str {
+                "user defined"
+            }
+        }
+
+        let circle = Shape::Circle(1);
+        assert!(circle.is_kind_circle());
+        assert!(!circle.is_kind_square());
+        assert_eq!(circle.is_circle(), "user defined");
+        assert!(ShapeKind::Square.is_kind_square());
+    }
+
+    #[test]
+    fn should_work_with_generics() {
+        #[derive(Kinded)]
+        #[kinded(predicates)]
+        enum Maybe<T> {
+            Just(T),
+            Nothing,
+        }
+
+        assert!(Maybe::Just(1).is_just());
+        assert!(Maybe::<i32>::Nothing.is_nothing());
+    }
+}
+
+mod accessors {
+    use alloc::string::{String, ToString};
+    use kinded::Kinded;
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(accessors)]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea { variety: String, caffeine: bool },
+        Juice(String, u32),
+    }
+
+    #[test]
+    fn should_access_single_field_by_ref() {
+        let coffee = Drink::Coffee("Espresso".to_string());
+        assert_eq!(coffee.as_coffee(), Some(&"Espresso".to_string()));
+        assert_eq!(coffee.as_tea(), None);
+        assert_eq!(Drink::Mate.as_coffee(), None);
+    }
+
+    #[test]
+    fn should_access_multiple_fields_by_ref() {
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        assert_eq!(tea.as_tea(), Some((&"Green".to_string(), &true)));
+
+        let juice = Drink::Juice("Orange".to_string(), 250);
+        assert_eq!(juice.as_juice(), Some((&"Orange".to_string(), &250)));
+    }
+
+    #[test]
+    fn should_access_fields_by_mut_ref() {
+        let mut coffee = Drink::Coffee("Espresso".to_string());
+        coffee.as_coffee_mut().unwrap().push_str(" Doppio");
+        assert_eq!(coffee, Drink::Coffee("Espresso Doppio".to_string()));
+        assert!(coffee.as_tea_mut().is_none());
+
+        let mut tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        let (_variety, caffeine) = tea.as_tea_mut().unwrap();
+        *caffeine = false;
+        assert_eq!(tea.as_tea(), Some((&"Green".to_string(), &false)));
+    }
+
+    #[test]
+    fn should_convert_into_payload() {
+        let coffee = Drink::Coffee("Espresso".to_string());
+        assert_eq!(coffee.into_coffee(), Ok("Espresso".to_string()));
+
+        let juice = Drink::Juice("Orange".to_string(), 250);
+        assert_eq!(juice.into_juice(), Ok(("Orange".to_string(), 250)));
+    }
+
+    #[test]
+    fn should_give_value_back_on_mismatch() {
+        assert_eq!(Drink::Mate.into_coffee(), Err(Drink::Mate));
+    }
+
+    #[test]
+    fn should_work_with_generics_and_single_variant() {
+        #[derive(Kinded)]
+        #[kinded(accessors)]
+        enum Wrapper<'a, T> {
+            Value(&'a T),
+        }
+
+        let value = 5;
+        let wrapper = Wrapper::Value(&value);
+        assert_eq!(wrapper.as_value(), Some(&&5));
+        assert_eq!(wrapper.into_value().ok(), Some(&5));
+    }
+}
+
+mod ref_enums {
+    use alloc::string::{String, ToString};
+    use kinded::Kinded;
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(ref_enum, mut_enum)]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea { variety: String, caffeine: bool },
+    }
+
+    #[test]
+    fn should_match_on_ref_enum() {
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        match tea.as_ref_enum() {
+            DrinkRef::Tea { variety, caffeine } => {
+                assert_eq!(variety, "Green");
+                assert!(*caffeine);
+            }
+            _ => panic!("Expected Tea"),
+        }
+
+        let coffee = Drink::Coffee("Espresso".to_string());
+        let DrinkRef::Coffee(name) = DrinkRef::from(&coffee) else {
+            panic!("Expected Coffee");
+        };
+        assert_eq!(name, "Espresso");
+    }
+
+    #[test]
+    fn should_mutate_through_mut_enum() {
+        let mut coffee = Drink::Coffee("Espresso".to_string());
+        if let DrinkMut::Coffee(name) = coffee.as_mut_enum() {
+            name.push_str(" Doppio");
+        }
+        assert_eq!(coffee, Drink::Coffee("Espresso Doppio".to_string()));
+
+        let mut tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        if let DrinkMut::Tea { caffeine, .. } = DrinkMut::from(&mut tea) {
+            *caffeine = false;
+        }
+        assert_eq!(
+            tea,
+            Drink::Tea {
+                variety: "Green".to_string(),
+                caffeine: false
+            }
+        );
+    }
+
+    #[test]
+    fn should_implement_kinded_for_views() {
+        fn compute_kind<T: Kinded>(val: &T) -> T::Kind {
+            val.kind()
+        }
+
+        let mut mate = Drink::Mate;
+        assert_eq!(mate.as_ref_enum().kind(), DrinkKind::Mate);
+        assert_eq!(compute_kind(&mate.as_ref_enum()), DrinkKind::Mate);
+        assert_eq!(compute_kind(&mate.as_mut_enum()), DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_copy_ref_enum() {
+        fn receive_copy<T: Copy>(_: T) {}
+
+        let coffee = Drink::Coffee("Espresso".to_string());
+        receive_copy(coffee.as_ref_enum());
+    }
+
+    #[test]
+    fn should_work_with_generics_and_lifetimes() {
+        #[derive(Kinded)]
+        #[kinded(ref_enum, mut_enum)]
+        enum Identifier<'a, I: Copy> {
+            Name(&'a str),
+            Id(I),
+        }
+
+        let mut id: Identifier<i32> = Identifier::Id(7);
+        if let IdentifierMut::Id(value) = id.as_mut_enum() {
+            *value += 1;
+        }
+        let IdentifierRef::Id(value) = id.as_ref_enum() else {
+            panic!("Expected Id");
+        };
+        assert_eq!(*value, 8);
+        assert_eq!(id.as_ref_enum().kind(), IdentifierKind::Id);
+    }
+}
+
+mod parts {
+    use alloc::string::{String, ToString};
+    use kinded::{KindMismatchError, Kinded};
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(parts)]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea { variety: String, caffeine: bool },
+    }
+
+    #[test]
+    fn should_split_into_parts() {
+        let (kind, payload) = Drink::Coffee("Espresso".to_string()).into_parts();
+        assert_eq!(kind, DrinkKind::Coffee);
+        let DrinkPayload::Coffee(name) = payload else {
+            panic!("Expected Coffee payload");
+        };
+        assert_eq!(name, "Espresso");
+
+        let (kind, payload) = Drink::Mate.into_parts();
+        assert_eq!(kind, DrinkKind::Mate);
+        assert_eq!(payload.kind(), DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_reassemble_from_parts() {
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        let (kind, payload) = tea.into_parts();
+        assert_eq!(
+            Drink::from_parts(kind, payload),
+            Ok(Drink::Tea {
+                variety: "Green".to_string(),
+                caffeine: true
+            })
+        );
+    }
+
+    #[test]
+    fn should_return_error_on_mismatch() {
+        let payload = DrinkPayload::Coffee("Espresso".to_string());
+        let error = Drink::from_parts(DrinkKind::Tea, payload).unwrap_err();
+        assert_eq!(
+            error,
+            KindMismatchError::new(DrinkKind::Tea, DrinkKind::Coffee)
+        );
+        assert_eq!(error.expected(), &DrinkKind::Tea);
+        assert_eq!(error.actual(), &DrinkKind::Coffee);
+        assert_eq!(error.to_string(), "Kind mismatch: expected Tea, got Coffee");
+    }
+
+    #[test]
+    fn should_work_with_generics() {
+        #[derive(Kinded)]
+        #[kinded(parts)]
+        enum Maybe<T> {
+            Just(T),
+            Nothing,
+        }
+
+        let (kind, payload) = Maybe::Just(5).into_parts();
+        assert_eq!(kind, MaybeKind::Just);
+        let restored = Maybe::from_parts(kind, payload).unwrap();
+        assert!(matches!(restored, Maybe::Just(5)));
+    }
+
+    #[test]
+    fn should_compare_kept_fields() {
+        #[derive(Kinded, Debug)]
+        #[kinded(parts, skip_derive(Kind, FromStr))]
+        enum Event {
+            Created {
+                #[kinded(keep)]
+                tenant_id: u32,
+                name: String,
+            },
+            Deleted(#[kinded(keep)] u32),
+        }
+
+        let payload = EventPayload::Deleted(1);
+        assert!(Event::from_parts(EventKind::Deleted(1), payload).is_ok());
+
+        let payload = EventPayload::Created {
+            tenant_id: 1,
+            name: "tea".to_string(),
+        };
+        let error = Event::from_parts(EventKind::Created { tenant_id: 2 }, payload).unwrap_err();
+        assert_eq!(error.actual(), &EventKind::Created { tenant_id: 1 });
+    }
+
+    #[test]
+    fn should_not_use_partial_eq_of_kind() {
+        #[derive(Kinded)]
+        #[kinded(parts, skip_derive(PartialEq, CrossPartialEq))]
+        enum Shape {
+            Circle(u32),
+            Square(u32),
+        }
+
+        // All the shapes are considered equal, but the parts must still match exactly
+        impl PartialEq for ShapeKind {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        assert!(Shape::from_parts(ShapeKind::Circle, ShapePayload::Circle(1)).is_ok());
+        assert!(Shape::from_parts(ShapeKind::Circle, ShapePayload::Square(1)).is_err());
+    }
+}
+
+mod payload_structs {
+    use alloc::string::{String, ToString};
+    use kinded::Kinded;
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(payload_structs, payload_derive(Debug, Clone, PartialEq))]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea { variety: String, caffeine: bool },
+    }
+
+    #[test]
+    fn should_generate_named_payload_struct() {
+        let payload = TeaPayload {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        assert_eq!(TeaPayload::KIND, DrinkKind::Tea);
+
+        let tea = Drink::from(payload.clone());
+        assert_eq!(
+            tea,
+            Drink::Tea {
+                variety: "Green".to_string(),
+                caffeine: true
+            }
+        );
+        assert_eq!(TeaPayload::try_from(tea), Ok(payload));
+    }
+
+    #[test]
+    fn should_generate_unnamed_payload_struct() {
+        let payload = CoffeePayload("Espresso".to_string());
+        assert_eq!(CoffeePayload::KIND, DrinkKind::Coffee);
+
+        let coffee: Drink = payload.into();
+        assert_eq!(coffee, Drink::Coffee("Espresso".to_string()));
+
+        let payload: CoffeePayload = coffee.try_into().unwrap();
+        assert_eq!(payload.0, "Espresso");
+    }
+
+    #[test]
+    fn should_give_value_back_on_mismatch() {
+        assert_eq!(CoffeePayload::try_from(Drink::Mate), Err(Drink::Mate));
+    }
+
+    #[test]
+    fn should_apply_payload_derive_to_parts_payload() {
+        #[derive(Kinded)]
+        #[kinded(parts, payload_derive(Debug, PartialEq))]
+        enum Shape {
+            Circle(u32),
+            Point,
+        }
+
+        let (_, payload) = Shape::Circle(3).into_parts();
+        assert_eq!(payload, ShapePayload::Circle(3));
+    }
+}
+
+mod cross_partial_eq {
+    use alloc::vec::Vec;
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+    }
+
+    #[test]
+    fn should_compare_main_enum_with_kind() {
+        let drink = Drink::Coffee("Espresso");
+        assert!(drink == DrinkKind::Coffee);
+        assert!(drink != DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_compare_kind_with_main_enum() {
+        let drink = Drink::Coffee("Espresso");
+        assert!(DrinkKind::Coffee == drink);
+
+        let drink_ref = &drink;
+        assert!(DrinkKind::Coffee == drink_ref);
+        assert!(DrinkKind::Mate != drink_ref);
+    }
+
+    #[test]
+    fn should_work_in_filters() {
+        let drinks = [Drink::Mate, Drink::Coffee("Latte"), Drink::Mate];
+        let mates: Vec<&Drink> = drinks
+            .iter()
+            .filter(|drink| DrinkKind::Mate == **drink)
+            .collect();
+        assert_eq!(mates.len(), 2);
+    }
+
+    #[test]
+    fn should_respect_generics_with_bounds() {
+        #[derive(Kinded)]
+        enum Maybe<'a, T: Clone>
+        where
+            T: core::fmt::Debug,
+        {
+            Just(&'a T),
+            Nothing,
+        }
+
+        let value = 3;
+        assert!(Maybe::Just(&value) == MaybeKind::Just);
+        assert!(MaybeKind::Nothing == Maybe::<i32>::Nothing);
+        assert_eq!(MaybeKind::from(&Maybe::Just(&value)), MaybeKind::Just);
+    }
+
+    #[test]
+    fn should_be_skippable() {
+        #[derive(Kinded)]
+        #[kinded(skip_derive(CrossPartialEq))]
+        enum Shape {
+            Circle(u32),
+        }
+
+        // User provided implementation does not conflict with the generated one
+        impl PartialEq<ShapeKind> for Shape {
+            fn eq(&self, _other: &ShapeKind) -> bool {
+                false
+            }
+        }
+
+        assert!(Shape::Circle(1) != ShapeKind::Circle);
+    }
+
+    #[test]
+    fn should_infer_compared_type_when_skipped() {
+        #[derive(Kinded)]
+        #[kinded(skip_derive(CrossPartialEq))]
+        enum Shape {
+            Circle(u32),
+        }
+
+        // With a single PartialEq implementation the type of the right side is inferred
+        assert!(ShapeKind::Circle == "circle".parse().unwrap());
+    }
+}
+
+mod from_kind {
+    use alloc::string::ToString;
+    use core::str::FromStr;
+    use kinded::{Kinded, NonUnitKindError};
+
+    #[derive(Kinded, Debug, PartialEq)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        Tea { caffeine: bool },
+    }
+
+    #[test]
+    fn should_construct_unit_variant_from_kind() {
+        assert_eq!(Drink::try_from(DrinkKind::Mate), Ok(Drink::Mate));
+    }
+
+    #[test]
+    fn should_return_error_for_variants_with_data() {
+        let error = Drink::try_from(DrinkKind::Coffee).unwrap_err();
+        assert_eq!(error, NonUnitKindError::new(DrinkKind::Coffee));
+        assert_eq!(error.kind(), &DrinkKind::Coffee);
+        assert_eq!(
+            error.to_string(),
+            "Cannot construct a value from kind Coffee: the variant has data"
+        );
+
+        let result: Result<Drink, _> = DrinkKind::Tea.try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_parse_unit_only_main_enum() {
+        #[derive(Kinded, Debug, PartialEq)]
+        #[kinded(display = "snake_case")]
+        enum Status {
+            Active,
+            OnHold,
+        }
+
+        assert_eq!("on_hold".parse::<Status>().unwrap(), Status::OnHold);
+        assert_eq!(Status::from_str("Active").unwrap(), Status::Active);
+
+        let error = "Archived".parse::<Status>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"Failed to parse "Archived" as StatusKind"#
+        );
+    }
+
+    #[test]
+    fn should_be_skippable() {
+        #[derive(Kinded, Debug, PartialEq)]
+        #[kinded(skip_derive(TryFrom, FromStr))]
+        enum Status {
+            Active,
+        }
+
+        // User provided implementations do not conflict with generated ones
+        impl TryFrom<StatusKind> for Status {
+            type Error = ();
+
+            fn try_from(_kind: StatusKind) -> Result<Self, ()> {
+                Err(())
+            }
+        }
+
+        impl FromStr for Status {
+            type Err = ();
+
+            fn from_str(_s: &str) -> Result<Self, ()> {
+                Ok(Status::Active)
+            }
+        }
+
+        assert_eq!(Status::try_from(StatusKind::Active), Err(()));
+        assert_eq!("anything".parse::<Status>(), Ok(Status::Active));
+    }
+
+    #[test]
+    fn should_skip_from_str_of_main_enum_only() {
+        #[derive(Kinded, Debug, PartialEq)]
+        #[kinded(skip_derive(MainFromStr))]
+        enum Status {
+            Active,
+            Archived,
+        }
+
+        impl FromStr for Status {
+            type Err = ();
+
+            fn from_str(_s: &str) -> Result<Self, ()> {
+                Ok(Status::Archived)
+            }
+        }
+
+        assert_eq!("active".parse::<StatusKind>().unwrap(), StatusKind::Active);
+        assert_eq!("active".parse::<Status>(), Ok(Status::Archived));
+    }
+}
+
+mod default_values {
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use kinded::Kinded;
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(default_values)]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea {
+            variety: String,
+            caffeine: bool,
+        },
+        #[kinded(default_with = default_juice)]
+        Juice {
+            fruit: String,
+            ml: u32,
+        },
+    }
+
+    fn default_juice() -> Drink {
+        Drink::Juice {
+            fruit: "Orange".to_string(),
+            ml: 250,
+        }
+    }
+
+    #[test]
+    fn should_construct_default_values() {
+        assert_eq!(DrinkKind::Mate.default_value(), Drink::Mate);
+        assert_eq!(
+            DrinkKind::Coffee.default_value(),
+            Drink::Coffee(String::new())
+        );
+        assert_eq!(
+            DrinkKind::Tea.default_value(),
+            Drink::Tea {
+                variety: String::new(),
+                caffeine: false
+            }
+        );
+    }
+
+    #[test]
+    fn should_use_default_with_override() {
+        assert_eq!(DrinkKind::Juice.default_value(), default_juice());
+    }
+
+    #[test]
+    fn should_construct_value_for_every_kind() {
+        let kinds: Vec<DrinkKind> = DrinkKind::all()
+            .iter()
+            .map(|kind| kind.default_value().kind())
+            .collect();
+        assert_eq!(kinds, DrinkKind::all());
+    }
+
+    #[test]
+    fn should_work_with_generics() {
+        #[derive(Kinded, Debug, PartialEq)]
+        #[kinded(default_values)]
+        enum Maybe<T> {
+            Just(T),
+            Many(Vec<T>),
+            Nothing,
+        }
+
+        let just: Maybe<i32> = MaybeKind::Just.default_value();
+        assert_eq!(just, Maybe::Just(0));
+
+        let many: Maybe<i32> = MaybeKind::Many.default_value();
+        assert_eq!(many, Maybe::Many(Vec::new()));
+    }
+}
+
+mod visitor {
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(visitor)]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea { variety: String, caffeine: bool },
+    }
+
+    struct Describe;
+
+    impl DrinkVisitor for Describe {
+        type Output = String;
+
+        fn visit_mate(&mut self) -> String {
+            "mate".to_string()
+        }
+
+        fn visit_coffee(&mut self, field_0: &String) -> String {
+            format!("coffee {field_0}")
+        }
+
+        fn visit_tea(&mut self, variety: &String, caffeine: &bool) -> String {
+            format!("tea {variety} {caffeine}")
+        }
+    }
+
+    #[test]
+    fn should_dispatch_to_visit_methods() {
+        assert_eq!(Drink::Mate.accept(&mut Describe), "mate");
+        assert_eq!(
+            Drink::Coffee("Espresso".to_string()).accept(&mut Describe),
+            "coffee Espresso"
+        );
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        assert_eq!(tea.accept(&mut Describe), "tea Green true");
+    }
+
+    #[test]
+    fn should_keep_state_in_visitor() {
+        struct CountCaffeine(u32);
+
+        impl DrinkVisitor for CountCaffeine {
+            type Output = ();
+
+            fn visit_mate(&mut self) {
+                self.0 += 1;
+            }
+
+            fn visit_coffee(&mut self, _: &String) {
+                self.0 += 1;
+            }
+
+            fn visit_tea(&mut self, _: &String, caffeine: &bool) {
+                if *caffeine {
+                    self.0 += 1;
+                }
+            }
+        }
+
+        let drinks = [
+            Drink::Mate,
+            Drink::Coffee("Latte".to_string()),
+            Drink::Tea {
+                variety: "Rooibos".to_string(),
+                caffeine: false,
+            },
+        ];
+        let mut counter = CountCaffeine(0);
+        for drink in &drinks {
+            drink.accept(&mut counter);
+        }
+        assert_eq!(counter.0, 2);
+    }
+
+    #[test]
+    fn should_fall_back_to_visit_any() {
+        #[derive(Kinded)]
+        #[kinded(visitor(fallback))]
+        enum Beverage {
+            Mate,
+            Coffee(String),
+            Tea { variety: String },
+        }
+
+        struct IsCoffee;
+
+        impl BeverageVisitor for IsCoffee {
+            type Output = bool;
+
+            fn visit_any(&mut self, kind: BeverageKind) -> bool {
+                assert_ne!(kind, BeverageKind::Coffee);
+                false
+            }
+
+            fn visit_coffee(&mut self, _: &String) -> bool {
+                true
+            }
+        }
+
+        assert!(!Beverage::Mate.accept(&mut IsCoffee));
+        assert!(Beverage::Coffee("Mocha".to_string()).accept(&mut IsCoffee));
+        let tea = Beverage::Tea {
+            variety: "Black".to_string(),
+        };
+        assert!(!tea.accept(&mut IsCoffee));
+    }
+
+    #[test]
+    fn should_work_with_generics() {
+        #[derive(Kinded)]
+        #[kinded(visitor)]
+        enum Maybe<'a, T: Copy> {
+            Just(&'a T),
+            Nothing,
+        }
+
+        struct Unwrap;
+
+        impl<'a> MaybeVisitor<'a, u8> for Unwrap {
+            type Output = u8;
+
+            fn visit_just(&mut self, field_0: &&'a u8) -> u8 {
+                **field_0
+            }
+
+            fn visit_nothing(&mut self) -> u8 {
+                0
+            }
+        }
+
+        assert_eq!(Maybe::Just(&7).accept(&mut Unwrap), 7);
+        assert_eq!(Maybe::<u8>::Nothing.accept(&mut Unwrap), 0);
+    }
+}
+
+mod markers {
+    use alloc::string::{String, ToString};
+    use kinded::{KindMarker, Kinded, Typed};
+
+    #[derive(Kinded, Debug, Clone, PartialEq)]
+    #[kinded(markers)]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea { variety: String, caffeine: bool },
+    }
+
+    #[test]
+    fn should_generate_marker_per_variant() {
+        assert_eq!(drink_kind::Mate::KIND, DrinkKind::Mate);
+        assert_eq!(drink_kind::Coffee::KIND, DrinkKind::Coffee);
+        assert_eq!(drink_kind::Tea::KIND, DrinkKind::Tea);
+    }
+
+    #[test]
+    fn should_convert_into_typed() {
+        let coffee = Drink::Coffee("Espresso".to_string());
+        let typed: Typed<Drink, drink_kind::Coffee> = coffee.clone().try_into_typed().unwrap();
+        assert_eq!(typed.kind(), DrinkKind::Coffee);
+        assert_eq!(*typed, coffee);
+        assert_eq!(typed.into_inner(), coffee);
+
+        let result = coffee.clone().try_into_typed::<drink_kind::Mate>();
+        assert_eq!(result, Err(coffee));
+    }
+
+    #[test]
+    fn should_access_payload() {
+        let coffee = Drink::Coffee("Espresso".to_string());
+        let mut typed = coffee.try_into_typed::<drink_kind::Coffee>().unwrap();
+        assert_eq!(typed.payload(), "Espresso");
+        typed.payload_mut().push_str(" Doppio");
+        assert_eq!(typed.into_payload(), "Espresso Doppio");
+
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        let typed = Typed::<_, drink_kind::Tea>::new(tea).unwrap();
+        let (variety, caffeine) = typed.payload();
+        assert_eq!(variety, "Green");
+        assert!(*caffeine);
+        assert_eq!(typed.into_payload(), ("Green".to_string(), true));
+
+        let mate = Drink::Mate.try_into_typed::<drink_kind::Mate>().unwrap();
+        assert_eq!(mate.into_payload(), ());
+    }
+
+    #[test]
+    fn should_express_variant_in_signature() {
+        fn brew(coffee: Typed<Drink, drink_kind::Coffee>) -> String {
+            coffee.into_payload()
+        }
+
+        let coffee = Drink::Coffee("Latte".to_string());
+        assert_eq!(brew(coffee.try_into_typed().unwrap()), "Latte");
+    }
+
+    mod nested {
+        use kinded::Kinded;
+
+        #[derive(Kinded)]
+        #[kinded(markers)]
+        pub(super) enum Size {
+            Small,
+            Large,
+        }
+    }
+
+    #[test]
+    fn should_keep_visibility_of_main_enum() {
+        assert_eq!(nested::size_kind::Small::KIND, nested::SizeKind::Small);
+        assert_eq!(nested::size_kind::Large::KIND, nested::SizeKind::Large);
+    }
+
+    #[test]
+    fn should_work_with_generics() {
+        #[derive(Kinded, Debug, PartialEq)]
+        #[kinded(markers)]
+        enum Maybe<'a, T: Copy> {
+            Just(&'a T),
+            Nothing,
+        }
+
+        let value = 5;
+        let just = Maybe::Just(&value)
+            .try_into_typed::<maybe_kind::Just>()
+            .unwrap();
+        assert_eq!(**just.payload(), 5);
+        assert!(
+            Maybe::<u8>::Nothing
+                .try_into_typed::<maybe_kind::Just>()
+                .is_err()
+        );
+    }
+}
+
+mod keep {
+    use alloc::string::{String, ToString};
+    use kinded::Kinded;
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(
+        predicates,
+        default_values,
+        ref_enum,
+        parts,
+        visitor(fallback),
+        skip_derive(Kind, FromStr)
+    )]
+    enum Event {
+        UserCreated {
+            #[kinded(keep)]
+            tenant_id: u32,
+            name: String,
+        },
+        Moved(String, #[kinded(keep)] i32, #[kinded(keep)] i32),
+        Ping,
+    }
+
+    fn user_created() -> Event {
+        Event::UserCreated {
+            tenant_id: 7,
+            name: "Alice".to_string(),
+        }
+    }
+
+    #[test]
+    fn should_copy_kept_fields_into_kind() {
+        assert_eq!(
+            user_created().kind(),
+            EventKind::UserCreated { tenant_id: 7 }
+        );
+        let moved = Event::Moved("north".to_string(), 3, -4);
+        assert_eq!(moved.kind(), EventKind::Moved(3, -4));
+        assert_eq!(Event::Ping.kind(), EventKind::Ping);
+    }
+
+    #[test]
+    fn should_compare_kind_with_kept_fields() {
+        assert_eq!(user_created(), EventKind::UserCreated { tenant_id: 7 });
+        assert_ne!(user_created(), EventKind::UserCreated { tenant_id: 8 });
+        assert_eq!(
+            EventKind::from(&user_created()),
+            EventKind::UserCreated { tenant_id: 7 }
+        );
+    }
+
+    #[test]
+    fn should_display_kind_name() {
+        assert_eq!(
+            EventKind::UserCreated { tenant_id: 7 }.to_string(),
+            "UserCreated"
+        );
+        assert_eq!(EventKind::Moved(1, 2).to_string(), "Moved");
+    }
+
+    #[test]
+    fn should_generate_predicates() {
+        assert!(EventKind::Moved(1, 2).is_moved());
+        assert!(!EventKind::Ping.is_moved());
+    }
+
+    #[test]
+    fn should_take_kept_fields_for_default_value() {
+        assert_eq!(
+            EventKind::UserCreated { tenant_id: 3 }.default_value(),
+            Event::UserCreated {
+                tenant_id: 3,
+                name: String::new()
+            }
+        );
+        assert_eq!(
+            EventKind::Moved(1, 2).default_value(),
+            Event::Moved(String::new(), 1, 2)
+        );
+    }
+
+    #[test]
+    fn should_work_with_ref_enum_and_parts() {
+        let event = user_created();
+        assert_eq!(
+            event.as_ref_enum().kind(),
+            EventKind::UserCreated { tenant_id: 7 }
+        );
+
+        let (kind, payload) = event.into_parts();
+        assert_eq!(kind, EventKind::UserCreated { tenant_id: 7 });
+        assert!(Event::from_parts(EventKind::UserCreated { tenant_id: 8 }, payload).is_err());
+    }
+
+    #[test]
+    fn should_pass_kind_to_visit_any() {
+        struct KindOf;
+
+        impl EventVisitor for KindOf {
+            type Output = EventKind;
+
+            fn visit_any(&mut self, kind: EventKind) -> EventKind {
+                kind
+            }
+        }
+
+        assert_eq!(
+            user_created().accept(&mut KindOf),
+            EventKind::UserCreated { tenant_id: 7 }
+        );
+        assert_eq!(
+            Event::Moved("south".to_string(), 5, 6).accept(&mut KindOf),
+            EventKind::Moved(5, 6)
+        );
+    }
+
+    #[test]
+    fn should_construct_unit_variants_from_kind() {
+        assert_eq!(Event::try_from(EventKind::Ping), Ok(Event::Ping));
+        assert!(Event::try_from(EventKind::Moved(1, 2)).is_err());
+    }
+}
+
+mod kind_with {
+    use alloc::string::{String, ToString};
+    use kinded::{Kind, Kinded};
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(
+        extra_kinds(Int, Float),
+        predicates,
+        ref_enum,
+        parts,
+        visitor(fallback)
+    )]
+    enum Value {
+        Null,
+        #[kinded(kind_with = number_kind)]
+        Number(f64),
+        Text(String),
+    }
+
+    fn number_kind(n: &f64) -> ValueKind {
+        if n.fract() == 0.0 {
+            ValueKind::Int
+        } else {
+            ValueKind::Float
+        }
+    }
+
+    #[test]
+    fn should_compute_kind_with_function() {
+        assert_eq!(Value::Number(1.0).kind(), ValueKind::Int);
+        assert_eq!(Value::Number(1.5).kind(), ValueKind::Float);
+        assert_eq!(Value::Null.kind(), ValueKind::Null);
+        assert_eq!(Value::Text("a".to_string()).kind(), ValueKind::Text);
+    }
+
+    #[test]
+    fn should_list_extra_kinds() {
+        assert_eq!(
+            ValueKind::all(),
+            [
+                ValueKind::Null,
+                ValueKind::Text,
+                ValueKind::Int,
+                ValueKind::Float
+            ]
+        );
+        assert_eq!(<ValueKind as Kind>::all().len(), 4);
+    }
+
+    #[test]
+    fn should_display_and_parse_extra_kinds() {
+        assert_eq!(ValueKind::Float.to_string(), "Float");
+        assert_eq!("int".parse::<ValueKind>().unwrap(), ValueKind::Int);
+        assert!("number".parse::<ValueKind>().is_err());
+    }
+
+    #[test]
+    fn should_generate_predicates_for_extra_kinds() {
+        assert!(ValueKind::Int.is_int());
+        assert!(!ValueKind::Int.is_float());
+        assert!(Value::Number(2.5).is_number());
+    }
+
+    #[test]
+    fn should_work_with_ref_enum_and_parts() {
+        let value = Value::Number(2.5);
+        assert_eq!(value.as_ref_enum().kind(), ValueKind::Float);
+
+        let (kind, payload) = value.into_parts();
+        assert_eq!(kind, ValueKind::Float);
+        assert_eq!(payload.kind(), ValueKind::Float);
+        assert!(Value::from_parts(ValueKind::Int, payload).is_err());
+    }
+
+    #[test]
+    fn should_pass_computed_kind_to_visit_any() {
+        struct KindOf;
+
+        impl ValueVisitor for KindOf {
+            type Output = ValueKind;
+
+            fn visit_any(&mut self, kind: ValueKind) -> ValueKind {
+                kind
+            }
+        }
+
+        assert_eq!(Value::Number(3.0).accept(&mut KindOf), ValueKind::Int);
+    }
+
+    #[test]
+    fn should_not_construct_extra_kinds() {
+        assert_eq!(Value::try_from(ValueKind::Null), Ok(Value::Null));
+        assert!(Value::try_from(ValueKind::Int).is_err());
+    }
+
+    #[test]
+    fn should_work_with_generics() {
+        #[derive(Kinded)]
+        #[kinded(extra_kinds(Empty, NonEmpty))]
+        enum Container<T> {
+            #[kinded(kind_with = list_kind)]
+            List(alloc::vec::Vec<T>),
+            Single(T),
+        }
+
+        fn list_kind<T>(list: &[T]) -> ContainerKind {
+            if list.is_empty() {
+                ContainerKind::Empty
+            } else {
+                ContainerKind::NonEmpty
+            }
+        }
+
+        assert_eq!(
+            Container::<u8>::List(alloc::vec![]).kind(),
+            ContainerKind::Empty
+        );
+        assert_eq!(
+            Container::List(alloc::vec![1]).kind(),
+            ContainerKind::NonEmpty
+        );
+        assert_eq!(Container::Single(1).kind(), ContainerKind::Single);
+    }
+}
+
+mod nested {
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(display = "snake_case")]
+    enum Drink {
+        Mate,
+        Coffee(String),
+    }
+
+    #[derive(Kinded)]
+    #[kinded(display = "snake_case")]
+    enum Snack {
+        Cookie,
+        IceCream { flavor: String },
+    }
+
+    #[derive(Kinded)]
+    #[kinded(display = "snake_case")]
+    enum Event {
+        Ping,
+        #[kinded(nested)]
+        Drink(Drink),
+        #[kinded(nested)]
+        Snack {
+            snack: Snack,
+        },
+    }
+
+    #[test]
+    fn should_return_kind_path() {
+        assert_eq!(Event::Ping.kind_path(), EventKindPath::Ping);
+        assert_eq!(
+            Event::Drink(Drink::Coffee("Latte".to_string())).kind_path(),
+            EventKindPath::Drink(DrinkKind::Coffee)
+        );
+        let event = Event::Snack {
+            snack: Snack::Cookie,
+        };
+        assert_eq!(event.kind_path(), EventKindPath::Snack(SnackKind::Cookie));
+        assert_eq!(event.kind(), EventKind::Snack);
+    }
+
+    #[test]
+    fn should_get_kind_of_path() {
+        assert_eq!(
+            EventKindPath::Drink(DrinkKind::Mate).kind(),
+            EventKind::Drink
+        );
+        assert_eq!(EventKindPath::Ping.kind(), EventKind::Ping);
+    }
+
+    #[test]
+    fn should_list_all_paths() {
+        assert_eq!(
+            EventKindPath::all(),
+            vec![
+                EventKindPath::Ping,
+                EventKindPath::Drink(DrinkKind::Mate),
+                EventKindPath::Drink(DrinkKind::Coffee),
+                EventKindPath::Snack(SnackKind::Cookie),
+                EventKindPath::Snack(SnackKind::IceCream),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_display_path() {
+        assert_eq!(EventKindPath::Ping.to_string(), "ping");
+        assert_eq!(
+            EventKindPath::Drink(DrinkKind::Coffee).to_string(),
+            "drink.coffee"
+        );
+        assert_eq!(
+            EventKindPath::Snack(SnackKind::IceCream).to_string(),
+            "snack.ice_cream"
+        );
+    }
+
+    #[derive(Kinded)]
+    #[kinded(display = "snake_case")]
+    enum Log {
+        #[kinded(nested)]
+        Event(Event),
+        #[kinded(nested)]
+        Retry(Option<u8>),
+    }
+
+    #[test]
+    fn should_nest_kind_paths_recursively() {
+        let log = Log::Event(Event::Drink(Drink::Mate));
+        assert_eq!(
+            log.kind_path(),
+            LogKindPath::Event(EventKindPath::Drink(DrinkKind::Mate))
+        );
+        assert_eq!(log.kind_path().to_string(), "event.drink.mate");
+        assert_eq!(
+            Log::Retry(None).kind_path(),
+            LogKindPath::Retry(kinded::OptionKind::None)
+        );
+        assert_eq!(Log::Retry(Some(1)).kind_path().to_string(), "retry.Some");
+    }
+
+    #[test]
+    fn should_list_all_nested_paths() {
+        let paths = LogKindPath::all();
+        assert_eq!(paths.len(), 7);
+        assert_eq!(paths[0], LogKindPath::Event(EventKindPath::Ping));
+        assert_eq!(
+            paths[4],
+            LogKindPath::Event(EventKindPath::Snack(SnackKind::IceCream))
+        );
+        assert_eq!(paths[6], LogKindPath::Retry(kinded::OptionKind::None));
+    }
+
+    #[test]
+    fn should_implement_kinded_path() {
+        use kinded::KindedPath;
+
+        fn labels<T: KindedPath>(value: &T) -> (T::KindPath, usize) {
+            (value.kind_path(), T::all_kind_paths().len())
+        }
+
+        assert_eq!(labels(&Drink::Mate), (DrinkKind::Mate, 2));
+        assert_eq!(labels(&Event::Ping), (EventKindPath::Ping, 5));
+    }
+}
+
+mod flatten {
+    use alloc::string::{String, ToString};
+    use kinded::{Kind, Kinded};
+
+    mod drinks {
+        use alloc::string::String;
+        use kinded::Kinded;
+
+        #[derive(Kinded)]
+        #[kinded(export)]
+        pub enum Drink {
+            Mate,
+            Coffee(String),
+        }
+
+        #[derive(Kinded)]
+        #[kinded(export)]
+        pub enum Snack<T> {
+            Cookie,
+            Cake(T),
+        }
+    }
+
+    use drinks::{Drink, Snack};
+
+    #[derive(Kinded)]
+    #[kinded(display = "snake_case", predicates, ref_enum, visitor(fallback))]
+    enum Event {
+        Ping,
+        #[kinded(flatten)]
+        Drink(Drink),
+        #[kinded(flatten)]
+        Snack {
+            snack: drinks::Snack<u8>,
+        },
+    }
+
+    #[test]
+    fn should_flatten_inner_kinds() {
+        assert_eq!(
+            EventKind::all(),
+            [
+                EventKind::Ping,
+                EventKind::DrinkMate,
+                EventKind::DrinkCoffee,
+                EventKind::SnackCookie,
+                EventKind::SnackCake
+            ]
+        );
+        assert_eq!(<EventKind as Kind>::all().len(), 5);
+    }
+
+    #[test]
+    fn should_dispatch_kind_through_inner_enum() {
+        assert_eq!(Event::Ping.kind(), EventKind::Ping);
+        assert_eq!(Event::Drink(Drink::Mate).kind(), EventKind::DrinkMate);
+        assert_eq!(
+            Event::Drink(Drink::Coffee("Latte".to_string())).kind(),
+            EventKind::DrinkCoffee
+        );
+        let event = Event::Snack {
+            snack: Snack::Cake(3),
+        };
+        assert_eq!(event.kind(), EventKind::SnackCake);
+        assert_eq!(event.as_ref_enum().kind(), EventKind::SnackCake);
+    }
+
+    #[test]
+    fn should_display_and_parse_flattened_kinds() {
+        assert_eq!(EventKind::DrinkCoffee.to_string(), "drink_coffee");
+        assert_eq!(
+            "snack_cookie".parse::<EventKind>().unwrap(),
+            EventKind::SnackCookie
+        );
+        assert!(EventKind::DrinkMate.is_drink_mate());
+    }
+
+    #[test]
+    fn should_pass_flattened_kind_to_visit_any() {
+        struct KindOf;
+
+        impl EventVisitor for KindOf {
+            type Output = EventKind;
+
+            fn visit_any(&mut self, kind: EventKind) -> EventKind {
+                kind
+            }
+        }
+
+        let event = Event::Drink(Drink::Coffee(String::new()));
+        assert_eq!(event.accept(&mut KindOf), EventKind::DrinkCoffee);
+    }
+
+    #[test]
+    fn should_not_construct_flattened_kinds() {
+        assert!(matches!(Event::try_from(EventKind::Ping), Ok(Event::Ping)));
+        assert!(Event::try_from(EventKind::DrinkMate).is_err());
+    }
+}
+
+mod secondary_kinds {
+    use alloc::string::{String, ToString};
+    use kinded::{Kind, Kinded};
+
+    #[derive(Kinded)]
+    #[kinded(display = "snake_case")]
+    #[kinded(kind = DrinkCategory, method = category, derive(Hash), display = "UPPERCASE", group(Hot = [Coffee, Tea], Cold = [Mate]))]
+    #[kinded(kind = DrinkName, method = name, skip_derive(Display))]
+    enum Drink {
+        Mate,
+        Coffee(String),
+        Tea { variety: String, caffeine: bool },
+    }
+
+    #[test]
+    fn should_map_variants_to_groups() {
+        assert_eq!(Drink::Mate.category(), DrinkCategory::Cold);
+        assert_eq!(
+            Drink::Coffee("Espresso".to_string()).category(),
+            DrinkCategory::Hot
+        );
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+            caffeine: true,
+        };
+        assert_eq!(tea.category(), DrinkCategory::Hot);
+        assert_eq!(DrinkCategory::from(&tea), DrinkCategory::Hot);
+        assert!(tea == DrinkCategory::Hot);
+    }
+
+    #[test]
+    fn should_keep_primary_kind_for_kinded_trait() {
+        let drink = Drink::Coffee("Latte".to_string());
+        assert_eq!(Kinded::kind(&drink), DrinkKind::Coffee);
+        assert_eq!(drink.name(), DrinkName::Coffee);
+        assert_eq!(
+            DrinkName::all(),
+            [DrinkName::Mate, DrinkName::Coffee, DrinkName::Tea]
+        );
+    }
+
+    #[test]
+    fn should_configure_each_kind_separately() {
+        assert_eq!(DrinkKind::Mate.to_string(), "mate");
+        assert_eq!(DrinkCategory::Hot.to_string(), "HOT");
+        assert_eq!(
+            "cold".parse::<DrinkCategory>().unwrap(),
+            DrinkCategory::Cold
+        );
+        assert_eq!(
+            <DrinkCategory as Kind>::all(),
+            [DrinkCategory::Hot, DrinkCategory::Cold]
+        );
+
+        fn assert_hash<T: core::hash::Hash>(_: T) {}
+        assert_hash(DrinkCategory::Hot);
+    }
+
+    #[test]
+    fn should_be_usable_in_const_context() {
+        const CATEGORY: DrinkCategory = Drink::Mate.category();
+        assert_eq!(CATEGORY, DrinkCategory::Cold);
+    }
+}
+
+mod kind_of {
+    use kinded::{Kind, Kinded};
+
+    mod api {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum EventType {
+            Created,
+            Deleted,
+            Ping,
+        }
+
+        impl kinded::Kind for EventType {
+            fn all() -> &'static [EventType] {
+                &[EventType::Created, EventType::Deleted, EventType::Ping]
+            }
+        }
+
+        // Doesn't implement `Kind`, like a type of a crate that doesn't depend on kinded
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Level {
+            Low,
+            High,
+        }
+    }
+
+    use api::EventType;
+
+    #[derive(Kinded)]
+    #[kinded(kind_of = api::EventType, predicates, ref_enum, parts, markers)]
+    enum Event {
+        Created {
+            id: u32,
+        },
+        #[kinded(as = Deleted)]
+        Removed(u32),
+        Ping,
+    }
+
+    #[derive(Kinded)]
+    #[kinded(kind_of = api::Level, skip_derive(Kinded), ref_enum, parts)]
+    enum Alert {
+        Low(u8),
+        High { code: u32 },
+    }
+
+    #[derive(Kinded)]
+    #[kinded(kind_of = EventType)]
+    enum Notification {
+        #[kinded(as = Created)]
+        UserCreated(u32),
+        #[kinded(as = Created)]
+        GroupCreated(u32),
+    }
+
+    #[test]
+    fn should_map_variants_onto_existing_kind() {
+        assert_eq!(Event::Created { id: 1 }.kind(), EventType::Created);
+        assert_eq!(Event::Removed(1).kind(), EventType::Deleted);
+        assert_eq!(Event::Ping.kind(), EventType::Ping);
+        assert_eq!(Notification::GroupCreated(7).kind(), EventType::Created);
+    }
+
+    #[test]
+    fn should_map_onto_existing_kind_without_kind_trait() {
+        use api::Level;
+
+        let alert = Alert::High { code: 500 };
+        assert_eq!(alert.kind(), Level::High);
+        assert_eq!(alert.as_ref_enum().kind(), Level::High);
+        assert_eq!(Level::from(&Alert::Low(1)), Level::Low);
+        assert!(Alert::Low(1) == Level::Low);
+    }
+
+    #[test]
+    fn should_implement_kinded_with_existing_kind() {
+        fn kind_of<T: Kinded>(value: &T) -> T::Kind {
+            value.kind()
+        }
+
+        assert_eq!(kind_of(&Event::Removed(2)), EventType::Deleted);
+        assert_eq!(kind_of(&Notification::UserCreated(2)), EventType::Created);
+        assert_eq!(<EventType as Kind>::all().len(), 3);
+    }
+
+    #[test]
+    fn should_convert_and_compare_with_existing_kind() {
+        let event = Event::Removed(3);
+        assert_eq!(EventType::from(&event), EventType::Deleted);
+        assert!(event == EventType::Deleted);
+        assert!(EventType::Deleted == event);
+    }
+
+    #[test]
+    fn should_support_features_of_main_enum() {
+        let event = Event::Removed(3);
+        assert!(event.is_removed());
+        assert_eq!(event.as_ref_enum().kind(), EventType::Deleted);
+
+        let (kind, payload) = event.into_parts();
+        assert_eq!(kind, EventType::Deleted);
+        assert!(matches!(
+            Event::from_parts(EventType::Deleted, payload),
+            Ok(Event::Removed(3))
+        ));
+
+        assert_eq!(
+            <event_type::Removed as kinded::KindMarker>::KIND,
+            EventType::Deleted
+        );
+    }
+}
+
+mod subset {
+    use alloc::string::{String, ToString};
+    use kinded::{Kind, Kinded, NotInSubsetError};
+
+    #[derive(Kinded)]
+    #[kinded(
+        display = "snake_case",
+        derive(Hash),
+        subset(
+            HotDrinkKind = [Coffee, Tea],
+            Cold = [Mate],
+            Drinkable = [Mate, Tea],
+            CaffeinatedDrinks(method = caffeinated) = [Coffee]
+        )
+    )]
+    enum Drink {
+        Mate,
+        #[kinded(rename = "black coffee")]
+        Coffee(String),
+        Tea {
+            variety: String,
+        },
+    }
+
+    #[derive(Kinded)]
+    #[kinded(subset(Numbers = [Int, Float]), extra_kinds(Int, Float))]
+    enum Value {
+        Null,
+        #[kinded(kind_with = number_kind)]
+        Number(f64),
+    }
+
+    fn number_kind(n: &f64) -> ValueKind {
+        if n.fract() == 0.0 {
+            ValueKind::Int
+        } else {
+            ValueKind::Float
+        }
+    }
+
+    #[test]
+    fn should_convert_subset_into_kind() {
+        assert_eq!(DrinkKind::from(HotDrinkKind::Tea), DrinkKind::Tea);
+        assert_eq!(DrinkKind::from(Cold::Mate), DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_try_to_convert_kind_into_subset() {
+        assert_eq!(
+            HotDrinkKind::try_from(DrinkKind::Coffee),
+            Ok(HotDrinkKind::Coffee)
+        );
+        let err = HotDrinkKind::try_from(DrinkKind::Mate).unwrap_err();
+        assert_eq!(err, NotInSubsetError::new(DrinkKind::Mate));
+        assert_eq!(err.to_string(), "Kind Mate is not in the subset");
+    }
+
+    #[test]
+    fn should_get_subset_kind_of_value() {
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+        };
+        assert_eq!(tea.hot_kind(), Some(HotDrinkKind::Tea));
+        assert_eq!(Drink::Mate.hot_kind(), None);
+        assert_eq!(Drink::Mate.cold(), Some(Cold::Mate));
+        assert_eq!(Value::Number(1.5).numbers(), Some(Numbers::Float));
+        assert_eq!(Value::Null.numbers(), None);
+    }
+
+    #[test]
+    fn should_name_subset_methods() {
+        assert_eq!(Drink::Mate.drinkable(), Some(Drinkable::Mate));
+        assert_eq!(
+            Drink::Coffee("Latte".to_string()).caffeinated(),
+            Some(CaffeinatedDrinks::Coffee)
+        );
+        assert_eq!(Drink::Mate.caffeinated(), None);
+    }
+
+    #[test]
+    fn should_share_kind_configuration() {
+        assert_eq!(
+            HotDrinkKind::all(),
+            [HotDrinkKind::Coffee, HotDrinkKind::Tea]
+        );
+        assert_eq!(<Cold as Kind>::all(), [Cold::Mate]);
+        assert_eq!(HotDrinkKind::Coffee.to_string(), "black coffee");
+        assert_eq!(Cold::Mate.to_string(), "mate");
+        assert_eq!("tea".parse::<HotDrinkKind>().unwrap(), HotDrinkKind::Tea);
+        assert!("mate".parse::<HotDrinkKind>().is_err());
+
+        fn assert_hash<T: core::hash::Hash>(_: T) {}
+        assert_hash(HotDrinkKind::Tea);
+    }
+
+    #[test]
+    fn should_be_usable_in_const_context() {
+        const HOT_KIND: Option<HotDrinkKind> = Drink::Mate.hot_kind();
+        assert_eq!(HOT_KIND, None);
+    }
+}
+
+mod categories {
+    use alloc::string::{String, ToString};
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    enum Drink {
+        #[kinded(category = "cold")]
+        Mate,
+        #[kinded(category = "hot", category = "caffeinated")]
+        Coffee(String),
+        #[kinded(category = "hot")]
+        #[kinded(category = "caffeinated")]
+        Tea {
+            variety: String,
+        },
+        Water,
+    }
+
+    #[test]
+    fn should_list_categories_of_kind() {
+        assert_eq!(
+            DrinkKind::Coffee.categories(),
+            [DrinkKindCategory::Hot, DrinkKindCategory::Caffeinated]
+        );
+        assert_eq!(DrinkKind::Mate.categories(), [DrinkKindCategory::Cold]);
+        assert!(DrinkKind::Water.categories().is_empty());
+        assert_eq!(
+            DrinkKindCategory::all(),
+            [
+                DrinkKindCategory::Cold,
+                DrinkKindCategory::Hot,
+                DrinkKindCategory::Caffeinated
+            ]
+        );
+    }
+
+    #[test]
+    fn should_list_members_of_category() {
+        assert_eq!(
+            DrinkKindCategory::Hot.members(),
+            [DrinkKind::Coffee, DrinkKind::Tea]
+        );
+        assert_eq!(DrinkKindCategory::Cold.members(), [DrinkKind::Mate]);
+    }
+
+    #[test]
+    fn should_check_membership() {
+        let tea = Drink::Tea {
+            variety: "Green".to_string(),
+        };
+        assert!(tea.kind().is_in(DrinkKindCategory::Caffeinated));
+        assert!(!DrinkKind::Mate.is_in(DrinkKindCategory::Hot));
+        assert!(!DrinkKind::Water.is_in(DrinkKindCategory::Cold));
+
+        const HOT_MEMBERS: &[DrinkKind] = DrinkKindCategory::Hot.members();
+        assert_eq!(HOT_MEMBERS.len(), 2);
+    }
+
+    #[test]
+    fn should_display_category() {
+        assert_eq!(DrinkKindCategory::Caffeinated.to_string(), "caffeinated");
+    }
+}
+
+mod relations {
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use kinded::{KindConflictError, Kinded};
+
+    #[derive(Kinded)]
+    enum Permission {
+        Read {
+            path: String,
+        },
+        Write {
+            path: String,
+        },
+        #[kinded(implies(Write))]
+        Delete {
+            path: String,
+        },
+        #[kinded(implies(Read, Delete))]
+        Admin,
+        #[kinded(implies(Read), conflicts(Write))]
+        ReadOnly,
+    }
+
+    #[test]
+    fn should_list_implied_kinds_transitively() {
+        assert_eq!(
+            PermissionKind::Admin.implies(),
+            [
+                PermissionKind::Read,
+                PermissionKind::Write,
+                PermissionKind::Delete
+            ]
+        );
+        assert_eq!(PermissionKind::Delete.implies(), [PermissionKind::Write]);
+        assert!(PermissionKind::Read.implies().is_empty());
+    }
+
+    #[test]
+    fn should_list_conflicts_in_both_directions() {
+        assert_eq!(
+            PermissionKind::ReadOnly.conflicts(),
+            [PermissionKind::Write]
+        );
+        assert_eq!(
+            PermissionKind::Write.conflicts(),
+            [PermissionKind::ReadOnly]
+        );
+        assert!(PermissionKind::Admin.conflicts().is_empty());
+    }
+
+    #[test]
+    fn should_compute_closure() {
+        let delete = Permission::Delete {
+            path: "/tmp".to_string(),
+        };
+        assert_eq!(
+            PermissionKind::closure([delete.kind(), PermissionKind::ReadOnly]),
+            [
+                PermissionKind::Read,
+                PermissionKind::Write,
+                PermissionKind::Delete,
+                PermissionKind::ReadOnly
+            ]
+        );
+        assert!(PermissionKind::closure(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn should_validate_conflicts_of_implied_kinds() {
+        assert_eq!(
+            PermissionKind::validate([PermissionKind::Read, PermissionKind::ReadOnly]),
+            Ok(())
+        );
+        let err = PermissionKind::validate([PermissionKind::Admin, PermissionKind::ReadOnly])
+            .unwrap_err();
+        assert_eq!(
+            err,
+            KindConflictError::new(PermissionKind::Write, PermissionKind::ReadOnly)
+        );
+        assert_eq!(err.to_string(), "Kind Write conflicts with ReadOnly");
+    }
+}
+
+mod transitions {
+    use alloc::string::ToString;
+    use kinded::{Kinded, TransitionError};
+
+    #[derive(Kinded)]
+    enum Order {
+        #[kinded(transitions(Paid, Cancelled))]
+        Pending,
+        #[kinded(transitions(Shipped, Cancelled))]
+        Paid {
+            amount: u32,
+        },
+        #[kinded(transitions(Delivered))]
+        Shipped(u32),
+        Delivered,
+        Cancelled,
+    }
+
+    #[test]
+    fn should_list_successors() {
+        assert_eq!(
+            OrderKind::Pending.successors(),
+            [OrderKind::Paid, OrderKind::Cancelled]
+        );
+        assert_eq!(OrderKind::Shipped.successors(), [OrderKind::Delivered]);
+        assert!(OrderKind::Delivered.successors().is_empty());
+    }
+
+    #[test]
+    fn should_check_transitions_between_kinds() {
+        const FROM_PENDING: [bool; 2] = [
+            OrderKind::Pending.can_transition_to(OrderKind::Paid),
+            OrderKind::Pending.can_transition_to(OrderKind::Delivered),
+        ];
+        assert_eq!(FROM_PENDING, [true, false]);
+        assert!(OrderKind::Paid.can_transition_to(OrderKind::Cancelled));
+        assert!(!OrderKind::Paid.can_transition_to(OrderKind::Pending));
+        assert!(!OrderKind::Shipped.can_transition_to(OrderKind::Shipped));
+    }
+
+    #[test]
+    fn should_report_initial_and_terminal_states() {
+        assert_eq!(OrderKind::initial_states(), [OrderKind::Pending]);
+        assert_eq!(
+            OrderKind::terminal_states(),
+            [OrderKind::Delivered, OrderKind::Cancelled]
+        );
+        assert!(OrderKind::Cancelled.is_terminal());
+        assert!(!OrderKind::Pending.is_terminal());
+    }
+
+    #[test]
+    fn should_check_transitions_between_values() {
+        let paid = Order::Paid { amount: 10 };
+        assert_eq!(Order::Pending.check_transition(&paid), Ok(()));
+        assert_eq!(paid.check_transition(&Order::Shipped(1)), Ok(()));
+
+        let err = Order::Delivered.check_transition(&paid).unwrap_err();
+        assert_eq!(
+            err,
+            TransitionError::new(OrderKind::Delivered, OrderKind::Paid)
+        );
+        assert_eq!(*err.from(), OrderKind::Delivered);
+        assert_eq!(*err.to(), OrderKind::Paid);
+        assert_eq!(
+            err.to_string(),
+            "Transition from Delivered to Paid is not allowed"
+        );
+    }
+}
+
+mod kind_derive {
+    use alloc::string::ToString;
+    use kinded::{Kind, ParseKindError};
+
+    #[derive(Kind, Debug, Clone, Copy, PartialEq, Eq)]
+    #[kinded(display = "snake_case", ignore_case)]
+    enum Color {
+        Red,
+        DarkGreen,
+        #[kinded(rename = "sky")]
+        Blue,
+    }
+
+    #[test]
+    fn should_list_all_variants() {
+        assert_eq!(Color::all(), [Color::Red, Color::DarkGreen, Color::Blue]);
+        assert_eq!(<Color as Kind>::all(), Color::all());
+    }
+
+    #[test]
+    fn should_implement_display() {
+        assert_eq!(Color::DarkGreen.to_string(), "dark_green");
+        assert_eq!(Color::Blue.to_string(), "sky");
+    }
+
+    #[test]
+    fn should_implement_from_str() {
+        assert_eq!("DarkGreen".parse::<Color>().unwrap(), Color::DarkGreen);
+        assert_eq!("dark-green".parse::<Color>().unwrap(), Color::DarkGreen);
+        assert_eq!("sky".parse::<Color>().unwrap(), Color::Blue);
+
+        let err: ParseKindError = "purple".parse::<Color>().unwrap_err();
+        assert_eq!(err.to_string(), r#"Failed to parse "purple" as Color"#);
+    }
+
+    #[test]
+    fn should_parse_ignoring_case() {
+        assert_eq!("RED".parse::<Color>().unwrap(), Color::Red);
+        assert_eq!("rEd".parse::<Color>().unwrap(), Color::Red);
+        assert_eq!("dArK_gReEn".parse::<Color>().unwrap(), Color::DarkGreen);
+        assert_eq!("DARKGREEN".parse::<Color>().unwrap(), Color::DarkGreen);
+        assert_eq!("SKY".parse::<Color>().unwrap(), Color::Blue);
+        assert!("Dark_Greens".parse::<Color>().is_err());
+    }
+
+    #[derive(Kind, Debug, Clone, Copy, PartialEq, Eq)]
+    #[kinded(skip_derive(Display))]
+    enum Shape {
+        Circle,
+        Square,
+    }
+
+    impl core::fmt::Display for Shape {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "shape")
+        }
+    }
+
+    #[test]
+    fn should_skip_display() {
+        assert_eq!(Shape::Circle.to_string(), "shape");
+        assert_eq!("square".parse::<Shape>().unwrap(), Shape::Square);
+    }
+}
+
+mod remote {
+    use alloc::string::ToString;
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    #[derive(kinded::Kinded)]
+    #[kinded(remote = core::net::IpAddr)]
+    enum IpAddrDef {
+        V4(Ipv4Addr),
+        V6(Ipv6Addr),
+    }
+
+    #[derive(kinded::Kinded)]
+    #[kinded(remote = core::option::Option, kind = Presence)]
+    enum OptionDef<T> {
+        Some(T),
+        None,
+    }
+
+    #[derive(kinded::Kinded)]
+    #[kinded(remote = core::ops::Bound)]
+    enum BoundDef<T> {
+        Included(T),
+        Excluded(T),
+        Unbounded,
+    }
+
+    #[derive(kinded::Kinded)]
+    #[kinded(remote = core::sync::atomic::Ordering, other = Unknown)]
+    #[non_exhaustive]
+    enum AtomicOrderingDef {
+        Relaxed,
+        Release,
+        Acquire,
+        AcqRel,
+        SeqCst,
+    }
+
+    #[test]
+    fn should_provide_kind_of_remote_enum() {
+        let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        assert_eq!(ip.kind(), IpAddrKind::V4);
+        assert_eq!(IpAddrKind::from(&ip), IpAddrKind::V4);
+        assert_eq!(IpAddrKind::V6.to_string(), "V6");
+        assert_eq!(IpAddrKind::all(), [IpAddrKind::V4, IpAddrKind::V6]);
+    }
+
+    #[test]
+    fn should_compare_remote_enum_with_kind() {
+        let ip = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        assert!(ip == IpAddrKind::V6);
+        assert!(IpAddrKind::V4 != ip);
+    }
+
+    #[test]
+    fn should_support_generic_remote_enums() {
+        assert_eq!(Some(1).kind(), Presence::Some);
+        assert_eq!(None::<u8>.kind(), Presence::None);
+        assert_eq!(core::ops::Bound::Excluded("a").kind(), BoundKind::Excluded);
+        assert_eq!(
+            core::ops::Bound::<u8>::Unbounded.kind(),
+            BoundKind::Unbounded
+        );
+    }
+
+    #[test]
+    fn should_support_non_exhaustive_remote_enums() {
+        use core::sync::atomic::Ordering;
+
+        assert_eq!(Ordering::SeqCst.kind(), OrderingKind::SeqCst);
+        assert_eq!(OrderingKind::Unknown.to_string(), "Unknown");
+        assert_eq!(OrderingKind::all().last(), Some(&OrderingKind::Unknown));
+    }
+}
+
+mod builtin_kinds {
+    extern crate std;
+
+    use alloc::borrow::Cow;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use core::cmp::Ordering;
+    use core::ops::Bound;
+    use kinded::{
+        BoundKind, CowKind, JsonValueKind, Kind, Kinded, OptionKind, OrderingKind, ResultKind,
+    };
+
+    fn kind_of<T: Kinded>(value: &T) -> T::Kind {
+        value.kind()
+    }
+
+    #[test]
+    fn should_provide_kinds_of_core_enums() {
+        assert_eq!(kind_of(&Some(1)), OptionKind::Some);
+        assert_eq!(kind_of(&None::<u8>), OptionKind::None);
+        assert_eq!(kind_of(&Ok::<u8, ()>(1)), ResultKind::Ok);
+        assert_eq!(kind_of(&Err::<u8, ()>(())), ResultKind::Err);
+        assert_eq!(kind_of(&1.cmp(&2)), OrderingKind::Less);
+        assert_eq!(kind_of(&Bound::Excluded(1)), BoundKind::Excluded);
+        assert_eq!(kind_of(&Bound::<u8>::Unbounded), BoundKind::Unbounded);
+
+        let borrowed: Cow<str> = Cow::Borrowed("tea");
+        let owned: Cow<str> = Cow::Owned(String::from("tea"));
+        assert_eq!(kind_of(&borrowed), CowKind::Borrowed);
+        assert_eq!(kind_of(&owned), CowKind::Owned);
+    }
+
+    #[test]
+    fn should_implement_kind_for_builtin_kinds() {
+        assert_eq!(
+            <OptionKind as Kind>::all(),
+            [OptionKind::Some, OptionKind::None]
+        );
+        assert_eq!(
+            OrderingKind::all(),
+            [
+                OrderingKind::Less,
+                OrderingKind::Equal,
+                OrderingKind::Greater
+            ]
+        );
+        assert_eq!(ResultKind::Err.to_string(), "Err");
+        assert_eq!("ok".parse::<ResultKind>().unwrap(), ResultKind::Ok);
+        assert_eq!(Ordering::Greater.kind(), OrderingKind::Greater);
+    }
+
+    #[test]
+    fn should_provide_kind_of_json_value() {
+        let value = serde_json::json!({ "drinks": ["tea"] });
+        assert_eq!(kind_of(&value), JsonValueKind::Object);
+        assert_eq!(kind_of(&value["drinks"]), JsonValueKind::Array);
+        assert_eq!(kind_of(&value["missing"]), JsonValueKind::Null);
+        assert_eq!(JsonValueKind::all().len(), 6);
+    }
+
+    #[test]
+    fn should_provide_kind_of_io_error() {
+        let err = std::io::Error::new(std::io::ErrorKind::NotFound, "no tea");
+        assert_eq!(kind_of(&err), std::io::ErrorKind::NotFound);
+        assert!(<std::io::ErrorKind as Kind>::all().contains(&std::io::ErrorKind::TimedOut));
+    }
+}
+
+mod blanket_impls {
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::string::String;
+    use alloc::sync::Arc;
+    use kinded::{Kinded, OptionKind};
+
+    #[derive(Kinded)]
+    enum Drink {
+        Mate,
+        Coffee(String),
+    }
+
+    fn kind_of<T: Kinded>(value: T) -> T::Kind {
+        value.kind()
+    }
+
+    #[test]
+    fn should_implement_kinded_for_references_and_pointers() {
+        let mut coffee = Drink::Coffee(String::from("espresso"));
+        assert_eq!(kind_of(&coffee), DrinkKind::Coffee);
+        assert_eq!(kind_of(&mut coffee), DrinkKind::Coffee);
+        assert_eq!(kind_of(&&coffee), DrinkKind::Coffee);
+        assert_eq!(kind_of(Box::new(Drink::Mate)), DrinkKind::Mate);
+        assert_eq!(kind_of(Rc::new(Drink::Mate)), DrinkKind::Mate);
+        assert_eq!(kind_of(Arc::new(Drink::Mate)), DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_implement_kinded_for_trait_objects() {
+        let drink: Box<dyn Kinded<Kind = DrinkKind>> = Box::new(Drink::Mate);
+        assert_eq!(kind_of(drink), DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_implement_kinded_for_kinds() {
+        assert_eq!(kind_of(DrinkKind::Coffee), DrinkKind::Coffee);
+        assert_eq!(kind_of(OptionKind::None), OptionKind::None);
+    }
+}
+
+mod delegate {
+    use alloc::string::String;
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    enum EventBody {
+        Created { name: String },
+        Deleted,
+    }
+
+    #[derive(Kinded)]
+    struct Envelope {
+        id: u64,
+        #[kinded(delegate)]
+        body: EventBody,
+    }
+
+    #[derive(Kinded)]
+    struct Tagged<'a, T>(&'a str, #[kinded(delegate)] T);
+
+    #[test]
+    fn should_delegate_kind_to_field() {
+        let envelope = Envelope {
+            id: 1,
+            body: EventBody::Created {
+                name: String::from("tea"),
+            },
+        };
+        assert_eq!(envelope.kind(), EventBodyKind::Created);
+        assert_eq!(Kinded::kind(&envelope), EventBodyKind::Created);
+    }
+
+    #[test]
+    fn should_delegate_kind_to_generic_field() {
+        let tagged = Tagged("audit", EventBody::Deleted);
+        assert_eq!(tagged.kind(), EventBodyKind::Deleted);
+
+        let nested = Tagged("outer", Tagged("inner", Some(1)));
+        assert_eq!(nested.kind(), kinded::OptionKind::Some);
+    }
+}